@@ -0,0 +1,10 @@
+use board::Move;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Action {
+    Move(i32),
+    Place(Move),
+    Hint,
+    Resign,
+    OfferDraw,
+}
@@ -0,0 +1,461 @@
+use io;
+use marker;
+use computer;
+use rules;
+use board;
+use tactics;
+use board::{Board, Move};
+use computer::{Evaluation, Score};
+use marker::Marker;
+use cli::{Format, Options};
+use variant::Variant;
+use variant;
+use action::Action;
+use reasoning::Reasoning;
+use rules::Rules;
+use std::collections::HashMap;
+
+const OFFSET: i32 = 1;
+const ROW_BREAK: char = '/';
+const FLAT: i32 = 2;
+const EMPTY_SPACES: &str = ".-_ ";
+const PERCENT: f64 = 100.0;
+
+pub fn analyse_position(position: &str, options: &Options) -> Result<String, String> {
+    let board = parse_position(position, options)?;
+    let rules = rules::find_rules(&board);
+    let scores = computer::score_candidates(rules, &board, options.search_budget, options.threads);
+    let scores = sort_scores(scores);
+    Ok(match options.format {
+        Format::Text => format_analysis(rules, &board, &scores),
+        Format::Json => format_analysis_json(rules, &scores),
+    })
+}
+
+pub fn explain_move<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    chosen: Move,
+    reasoning: Reasoning,
+) -> String {
+    let player = rules.find_current_player(board);
+    let mut explanation = format!(
+        "{} played {}{}",
+        marker::inspect(&player),
+        rules.format_move(&chosen),
+        describe_tactics(rules, board, chosen)
+    );
+    match reasoning {
+        Reasoning::Scores(scores) => {
+            for (next_move, score) in sort_scores(scores) {
+                explanation += &format!(
+                    "\n{}: {}{}",
+                    rules.format_move(&next_move),
+                    io::describe_outlook(&computer::evaluate(&score), score.value),
+                    describe_tactics(rules, board, next_move)
+                );
+            }
+        }
+        Reasoning::Visits(statistics) => {
+            for move_statistics in statistics {
+                explanation += &format!(
+                    "\n{}: {} visits, {:.0}% won{}",
+                    rules.format_move(&move_statistics.next_move),
+                    move_statistics.visits,
+                    move_statistics.win_rate * PERCENT,
+                    describe_tactics(rules, board, move_statistics.next_move)
+                );
+            }
+        }
+        Reasoning::Rule(rule) => explanation += &format!("\nRule: {}", io::describe_rule(&rule)),
+    }
+    explanation
+}
+
+fn describe_tactics<R: Rules + ?Sized>(rules: &R, board: &Board, next_move: Move) -> String {
+    let tactics: Vec<&str> = tactics::find_tactics(rules, board, next_move)
+        .iter()
+        .map(io::describe_tactic)
+        .collect();
+    if tactics.is_empty() {
+        String::new()
+    } else {
+        format!(", {}", tactics.join(", "))
+    }
+}
+
+fn sort_scores(scores: HashMap<Move, Score>) -> Vec<(Move, Score)> {
+    let mut scores: Vec<(Move, Score)> = scores.into_iter().collect();
+    scores.sort_by(|first, second| {
+        second
+            .1
+            .value
+            .cmp(&first.1.value)
+            .then(first.0.space.cmp(&second.0.space))
+            .then(marker::inspect(&first.0.marker).cmp(&marker::inspect(&second.0.marker)))
+    });
+    scores
+}
+
+pub fn parse_position(position: &str, options: &Options) -> Result<Board, String> {
+    let variant = options.variant.unwrap_or(Variant::Standard);
+    if position
+        .chars()
+        .all(|character| character.is_ascii_digit() || character == ',')
+        || (variant == Variant::Wild || variant == Variant::OrderAndChaos)
+            && position.contains(',')
+        || variant == Variant::Numerical
+    {
+        parse_moves(position, options)
+    } else {
+        parse_grid(position, options)
+    }
+}
+
+fn parse_moves(position: &str, options: &Options) -> Result<Board, String> {
+    let mut board = build_board(options.size, options);
+    let rules = rules::find_rules(&board);
+    for (turn, text) in position.split(',').filter(|text| !text.is_empty()).enumerate() {
+        let next_move = match io::parse_action(text) {
+            Some(Action::Move(space)) => Move {
+                space,
+                marker: rules.find_default_marker(&board),
+            },
+            Some(Action::Place(next_move)) => next_move,
+            _ => return Err(format!("Invalid move: {}", text)),
+        };
+        if rules.is_game_over(&board) {
+            return Err(format!("The game is already over before move {}", turn + 1));
+        }
+        if !rules.is_move_legal(&board, &next_move) {
+            return Err(format!("Space {} can not be played", text));
+        }
+        board = board.play(rules, next_move);
+    }
+    Ok(board)
+}
+
+fn parse_grid(position: &str, options: &Options) -> Result<Board, String> {
+    match options.variant {
+        Some(Variant::Ultimate) => {
+            return Err("An ultimate position must be given as a list of moves".to_string())
+        }
+        Some(Variant::Notakto) => {
+            return Err("A notakto position must be given as a list of moves".to_string())
+        }
+        _ => {}
+    }
+    let mut x_spaces: Vec<i32> = Vec::new();
+    let mut o_spaces: Vec<i32> = Vec::new();
+    let mut grid_moves: Vec<Move> = Vec::new();
+    let cells: Vec<char> = position
+        .chars()
+        .filter(|character| *character != ROW_BREAK)
+        .collect();
+    for (space, cell) in cells.iter().enumerate() {
+        match cell.to_ascii_uppercase() {
+            'X' => {
+                x_spaces.push(space as i32);
+                grid_moves.push(Move {
+                    space: space as i32,
+                    marker: Marker::X,
+                });
+            }
+            'O' => {
+                o_spaces.push(space as i32);
+                grid_moves.push(Move {
+                    space: space as i32,
+                    marker: Marker::O,
+                });
+            }
+            empty if EMPTY_SPACES.contains(empty) => {}
+            other => return Err(format!("Invalid space in grid: {}", other)),
+        }
+    }
+    let size = (cells.len() as f64)
+        .powf(1.0 / options.dimensions as f64)
+        .round() as i32;
+    if size.pow(options.dimensions as u32) != cells.len() as i32 {
+        let shape = if options.dimensions > FLAT { "a cube" } else { "square" };
+        return Err(format!("A grid of {} spaces is not {}", cells.len(), shape));
+    }
+    let board = build_board(size, options);
+    let rules = rules::find_rules(&board);
+    if rules.chooses_markers() {
+        return Ok(grid_moves
+            .into_iter()
+            .fold(board, |board, next_move| board.play(rules, next_move)));
+    }
+    let (first, second, first_player) = if o_spaces.len() > x_spaces.len() {
+        (o_spaces, x_spaces, Marker::O)
+    } else {
+        (x_spaces, o_spaces, Marker::X)
+    };
+    if first.len() > second.len() + 1 {
+        return Err("The grid has too many moves for one player".to_string());
+    }
+    let mut board = board.with_first_player(first_player);
+    for (index, space) in first.iter().enumerate() {
+        board = board.place_marker(rules, *space);
+        if let Some(reply) = second.get(index) {
+            board = board.place_marker(rules, *reply);
+        }
+    }
+    Ok(board)
+}
+
+fn build_board(size: i32, options: &Options) -> Board {
+    let board = board::build_board(size)
+        .with_dimensions(options.dimensions)
+        .with_win_length(options.win_length.unwrap_or(size).min(size));
+    variant::build_board(options.variant.unwrap_or(Variant::Standard), board, options.boards)
+}
+
+fn format_analysis<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    scores: &[(Move, Score)],
+) -> String {
+    let player = rules.find_current_player(board);
+    let mut analysis = rules.format_board(board);
+    if scores.is_empty() {
+        analysis += "The game is over";
+        return analysis;
+    }
+    analysis += &format!("{} to move", marker::inspect(&player));
+    for &(next_move, score) in scores {
+        analysis += &format!(
+            "\n{}: {}",
+            rules.format_move(&next_move),
+            io::describe_evaluation(&player, &computer::evaluate(&score))
+        );
+    }
+    analysis
+}
+
+fn format_analysis_json<R: Rules + ?Sized>(rules: &R, scores: &[(Move, Score)]) -> String {
+    let moves: Vec<String> = scores
+        .iter()
+        .map(|&(next_move, score)| {
+            let (result, moves) = match computer::evaluate(&score) {
+                Evaluation::Win(moves) => ("win", moves),
+                Evaluation::Loss(moves) => ("loss", moves),
+                Evaluation::Draw => ("draw", 0),
+                Evaluation::Unknown => ("unknown", 0),
+            };
+            let marker = match next_move.marker {
+                Marker::Number(value) => format!(",\"value\":{}", value),
+                marker if rules.chooses_markers() => {
+                    format!(",\"marker\":\"{}\"", marker::inspect(&marker))
+                }
+                _ => String::new(),
+            };
+            format!(
+                "{{\"space\":{}{},\"result\":\"{}\",\"moves\":{}}}",
+                next_move.space + OFFSET,
+                marker,
+                result,
+                moves
+            )
+        })
+        .collect();
+    format!("[{}]", moves.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cli::default_options;
+    use mcts::Statistics;
+    use strategy::Rule;
+
+    #[test]
+    fn parses_a_list_of_moves() {
+        let board = parse_position("1,5,2", &default_options()).unwrap();
+        assert_eq!(&vec![0, 4, 1], board.get_spaces());
+    }
+
+    #[test]
+    fn rejects_a_move_that_is_taken() {
+        assert_eq!(
+            Err("Space 5 can not be played".to_string()),
+            parse_position("5,5", &default_options()).map(|_board| ())
+        );
+    }
+
+    #[test]
+    fn rejects_a_move_after_the_game_is_over() {
+        assert_eq!(
+            Err("The game is already over before move 6".to_string()),
+            parse_position("1,4,2,5,3,6", &default_options()).map(|_board| ())
+        );
+    }
+
+    #[test]
+    fn parses_a_grid() {
+        let board = parse_position("X.O/.X./...", &default_options()).unwrap();
+        assert_eq!(&vec![0, 2, 4], board.get_spaces());
+        assert_eq!(Marker::O, rules::find_rules(&board).find_current_player(&board));
+    }
+
+    #[test]
+    fn parses_a_grid_where_o_moved_first() {
+        let board = parse_position("o...x...o", &default_options()).unwrap();
+        assert_eq!(&Marker::O, board.get_first_player());
+        assert_eq!(Marker::X, rules::find_rules(&board).find_current_player(&board));
+    }
+
+    #[test]
+    fn rejects_a_grid_that_is_not_square() {
+        assert_eq!(
+            Err("A grid of 8 spaces is not square".to_string()),
+            parse_position("X.O.X...", &default_options()).map(|_board| ())
+        );
+    }
+
+    #[test]
+    fn parses_a_grid_of_a_cube() {
+        let options = Options {
+            dimensions: 3,
+            ..default_options()
+        };
+        let grid = format!("X{}O{}", ".".repeat(20), ".".repeat(42));
+        let board = parse_position(&grid, &options).unwrap();
+        assert_eq!(&4, board.get_size());
+        assert_eq!(&vec![0, 21], board.get_spaces());
+    }
+
+    #[test]
+    fn rejects_a_grid_with_too_many_moves_for_one_player() {
+        assert_eq!(
+            Err("The grid has too many moves for one player".to_string()),
+            parse_position("XX.X.....", &default_options()).map(|_board| ())
+        );
+    }
+
+    #[test]
+    fn prints_the_value_of_every_move() {
+        let analysis = analyse_position("1,5,2,7", &default_options()).unwrap();
+        assert!(analysis.ends_with(
+            "X to move\n3: X wins in 1\n4: O wins in 1\n6: O wins in 1\n\
+             8: O wins in 1\n9: O wins in 1"
+        ));
+    }
+
+    #[test]
+    fn explains_the_move_the_computer_chose() {
+        let board = board::tests::set_up_board(3, vec![0, 4, 8, 1]);
+        let rules = rules::find_rules(&board);
+        let scores = computer::score_moves(rules, &board, computer::SINGLE_THREAD);
+        let chosen = Move {
+            space: 7,
+            marker: Marker::X,
+        };
+        assert_eq!(
+            "X played 8, blocks a threat\n8: draw, blocks a threat\n\
+             3: loss in 1\n4: loss in 1\n6: loss in 1\n7: loss in 1, forks",
+            explain_move(rules, &board, chosen, Reasoning::Scores(scores))
+        );
+    }
+
+    #[test]
+    fn explains_a_move_by_its_visits_or_its_rule() {
+        let board = board::tests::set_up_board(3, vec![0, 4, 8]);
+        let rules = rules::find_rules(&board);
+        let chosen = Move {
+            space: 1,
+            marker: Marker::O,
+        };
+        let statistics = vec![Statistics {
+            next_move: chosen,
+            visits: 412,
+            win_rate: 0.614,
+        }];
+        assert_eq!(
+            "O played 2\n2: 412 visits, 61% won",
+            explain_move(rules, &board, chosen, Reasoning::Visits(statistics))
+        );
+        assert_eq!(
+            "O played 2\nRule: take an empty side",
+            explain_move(rules, &board, chosen, Reasoning::Rule(Rule::EmptySide))
+        );
+    }
+
+    #[test]
+    fn prints_the_value_of_every_move_as_json() {
+        let options = Options {
+            format: Format::Json,
+            ..default_options()
+        };
+        assert_eq!(
+            Ok("[{\"space\":3,\"result\":\"win\",\"moves\":1},\
+                {\"space\":4,\"result\":\"loss\",\"moves\":1},\
+                {\"space\":6,\"result\":\"loss\",\"moves\":1},\
+                {\"space\":8,\"result\":\"loss\",\"moves\":1},\
+                {\"space\":9,\"result\":\"loss\",\"moves\":1}]"
+                .to_string()),
+            analyse_position("1,5,2,7", &options)
+        );
+    }
+
+    #[test]
+    fn parses_moves_with_markers_in_wild() {
+        let options = Options {
+            variant: Some(Variant::Wild),
+            ..default_options()
+        };
+        let board = parse_position("1o,5,2o", &options).unwrap();
+        assert_eq!(&vec![Marker::O, Marker::O, Marker::O], board.get_markers());
+    }
+
+    #[test]
+    fn prints_the_marker_of_every_move_in_wild() {
+        let options = Options {
+            variant: Some(Variant::Wild),
+            ..default_options()
+        };
+        let analysis = analyse_position("OO./XX./OXX", &options).unwrap();
+        assert!(analysis.contains("O to move\n3o: O wins in 1\n6x: O wins in 1\n"));
+    }
+
+    #[test]
+    fn rejects_a_move_outside_the_target_sub_board_in_ultimate() {
+        let options = Options {
+            variant: Some(Variant::Ultimate),
+            ..default_options()
+        };
+        assert_eq!(
+            Err("Space 1 can not be played".to_string()),
+            parse_position("41,1", &options).map(|_board| ())
+        );
+        assert_eq!(&vec![40, 30], parse_position("41,31", &options).unwrap().get_spaces());
+    }
+
+    #[test]
+    fn prints_the_number_of_every_move_in_numerical() {
+        let options = Options {
+            variant: Some(Variant::Numerical),
+            ..default_options()
+        };
+        let analysis = analyse_position("1=1,5=8,9=7,3=2,7=9,4=4", &options).unwrap();
+        assert!(analysis.contains("X to move\n6=3: X wins in 1\n"));
+    }
+
+    #[test]
+    fn rejects_a_move_with_a_number_of_the_other_player_in_numerical() {
+        let options = Options {
+            variant: Some(Variant::Numerical),
+            ..default_options()
+        };
+        assert_eq!(
+            Err("Space 5=3 can not be played".to_string()),
+            parse_position("1=1,5=3", &options).map(|_board| ())
+        );
+    }
+
+    #[test]
+    fn reports_a_finished_game() {
+        let analysis = analyse_position("1,4,2,5,3", &default_options()).unwrap();
+        assert!(analysis.ends_with("The game is over"));
+    }
+}
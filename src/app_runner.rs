@@ -1,69 +1,308 @@
 use io;
 use board;
-use game;
 use players;
 use game_types;
 use marker;
+use random;
+use report;
+use computer;
+use analysis;
+use variant;
+use rules;
+use outcome;
+use clock;
 use io::display;
 use io::clear_screen;
-use board::Board;
+use board::{Board, Move};
 use players::Players;
-use board_printer::format_board;
+use cli::{Format, Options};
+use random::Random;
+use action::Action;
+use marker::Marker;
+use variant::Variant;
+use rules::Rules;
+use outcome::Outcome;
+use clock::Clock;
+use computer::Budget;
+use reasoning::Reasoning;
+use std::time::{Duration, Instant};
 
-const INVALID_VALUE: i32 = -1;
-const NUMBER_OF_ROWS: i32 = 3;
 const YES: i32 = 1;
 
-pub fn start() {
-    let mut board = setup_board();
-    let players = setup_players();
-    while !game::is_game_over(&board) {
-        board = single_turn(board, &players);
+struct Progress {
+    hints: Vec<Marker>,
+    clock: Option<Clock>,
+    explanation: String,
+}
+
+pub fn start(options: &Options) {
+    let seed = options.seed.unwrap_or_else(random::seed_from_clock);
+    let mut random = random::build_random(seed);
+    let mut results: Vec<Outcome> = Vec::new();
+    loop {
+        let outcome = play_game(options, &mut random, results.len() + 1);
+        results.push(outcome);
+        if !keep_playing(options, results.len()) {
+            break;
+        }
+    }
+    if options.games.is_some() {
+        display(&report::format_summary(options.format, &results));
     }
-    end_of_game(&board);
 }
 
-fn setup_board() -> Board {
-    io::clear_screen();
-    display(io::TITLE);
-    board::build_board(NUMBER_OF_ROWS)
+fn play_game(options: &Options, random: &mut Random, number: usize) -> Outcome {
+    let board = setup_board(options);
+    let players = setup_players(options);
+    let board = setup_rules(board, options);
+    let rules = rules::find_rules(&board);
+    let (board, outcome, progress) = run_game(rules, board, &players, options, random);
+    end_of_game(rules, &board, &outcome, options, number, &progress);
+    outcome
 }
 
-fn setup_players() -> Vec<Players> {
-    let players = io::ask_player_type();
-    game_types::create_players(players)
+fn run_game<R: Rules + ?Sized>(
+    rules: &R,
+    board: Board,
+    players: &[Players],
+    options: &Options,
+    random: &mut Random,
+) -> (Board, Outcome, Progress) {
+    let mut board = board;
+    let mut progress = Progress {
+        hints: Vec::new(),
+        clock: options.time_control.map(clock::build_clock),
+        explanation: String::new(),
+    };
+    let mut outcome = rules.find_outcome(&board);
+    while !outcome::is_over(&outcome) {
+        if options.format == Format::Text {
+            show_user_state_of_game(rules, &board, &progress);
+        }
+        let previous_board = board.clone();
+        let (next_board, agreed_outcome, reasoning) =
+            single_turn(rules, board, players, options, random, &mut progress);
+        board = next_board;
+        outcome = agreed_outcome.unwrap_or_else(|| rules.find_outcome(&board));
+        progress.explanation = match reasoning {
+            Some(reasoning) if options.verbose => {
+                explain_turn(rules, &previous_board, &board, reasoning)
+            }
+            _ => String::new(),
+        };
+    }
+    (board, outcome, progress)
 }
 
-fn single_turn(board: Board, players: &[Players]) -> Board {
-    show_user_state_of_game(&board);
-    let space = select_a_space(&board, players);
-    board.place_marker(space)
+fn explain_turn<R: Rules + ?Sized>(
+    rules: &R,
+    previous_board: &Board,
+    board: &Board,
+    reasoning: Reasoning,
+) -> String {
+    let chosen = Move {
+        space: *board.get_spaces().last().expect("A move was played"),
+        marker: *board.get_markers().last().expect("A move was played"),
+    };
+    analysis::explain_move(rules, previous_board, chosen, reasoning)
 }
 
-fn end_of_game(board: &Board) {
-    clear_screen();
-    display(&io::alert_winner(&game::find_winner(board)));
-    display(&format_board(board));
-    if io::ask_play_again() == YES {
-        start()
+fn setup_board(options: &Options) -> Board {
+    if options.format == Format::Text {
+        io::clear_screen();
+        display(io::TITLE);
+    }
+    board::build_board(options.size)
+        .with_dimensions(options.dimensions)
+        .with_win_length(options.win_length.unwrap_or(options.size))
+        .with_first_player(options.first)
+}
+
+fn setup_rules(board: Board, options: &Options) -> Board {
+    let variant = match (options.variant, options.mode) {
+        (Some(variant), _) => variant,
+        (None, Some(_)) => Variant::Standard,
+        (None, None) => variant::choose(io::ask_rules()),
+    };
+    variant::build_board(variant, board, options.boards)
+}
+
+fn setup_players(options: &Options) -> Vec<Players> {
+    let players = match options.mode {
+        Some(mode) => mode,
+        None => io::ask_player_type(),
+    };
+    let players = game_types::create_players(players, options.difficulty, options.threads);
+    let players = game_types::switch_to_mcts(players, &options.mcts_players, options.mcts);
+    game_types::switch_to_strategy(players, &options.strategy_players)
+}
+
+fn single_turn<R: Rules + ?Sized>(
+    rules: &R,
+    board: Board,
+    players: &[Players],
+    options: &Options,
+    random: &mut Random,
+    progress: &mut Progress,
+) -> (Board, Option<Outcome>, Option<Reasoning>) {
+    let current_player_marker = rules.find_current_player(&board);
+    let player = find_player(players, &current_player_marker);
+    let budget = find_budget(options, &progress.clock, &current_player_marker);
+    let details = format_details(progress);
+    let started = Instant::now();
+    let mut paused = Duration::from_secs(0);
+    let mut notice = String::new();
+    loop {
+        let forfeit =
+            find_forfeit(&progress.clock, player, &current_player_marker, &started, paused);
+        if forfeit.is_some() {
+            return (board, forfeit, None);
+        }
+        let (action, reasoning) =
+            players::choose_action(rules, player, &board, random, &notice, &details, budget);
+        let next_board = match action {
+            Action::Move(space) => board.clone().place_marker(rules, space),
+            Action::Place(next_move) => board.clone().play(rules, next_move),
+            Action::Hint => {
+                progress.hints.push(current_player_marker);
+                let hint_started = Instant::now();
+                notice = give_hint(rules, &board, budget, options.threads);
+                paused += hint_started.elapsed();
+                continue;
+            }
+            Action::Resign => {
+                let resigned = Outcome::Resigned {
+                    player: current_player_marker,
+                };
+                return (board, Some(resigned), None);
+            }
+            Action::OfferDraw => {
+                let opponent = marker::opponent(&current_player_marker);
+                let opponent_player = find_player(players, &opponent);
+                let opponent_budget = find_budget(options, &progress.clock, &opponent);
+                if players::accepts_draw(rules, opponent_player, &board, random, opponent_budget) {
+                    return (board, Some(Outcome::DrawAgreed), None);
+                }
+                notice = io::decline_draw(&opponent);
+                continue;
+            }
+        };
+        let forfeit =
+            find_forfeit(&progress.clock, player, &current_player_marker, &started, paused);
+        if forfeit.is_some() {
+            return (board, forfeit, None);
+        }
+        if next_board.get_spaces().len() == board.get_spaces().len() {
+            notice = io::reject_move();
+            continue;
+        }
+        progress.clock = progress
+            .clock
+            .take()
+            .map(|clock| clock.record_move(&current_player_marker, started.elapsed() - paused));
+        return (next_board, None, reasoning);
+    }
+}
+
+fn find_forfeit(
+    clock: &Option<Clock>,
+    player: &Players,
+    marker: &Marker,
+    started: &Instant,
+    paused: Duration,
+) -> Option<Outcome> {
+    let clock = clock.as_ref()?;
+    if players::is_human(player) && clock.has_run_out(marker, started.elapsed() - paused) {
+        Some(Outcome::TimeForfeit { player: *marker })
+    } else {
+        None
+    }
+}
+
+fn find_budget(options: &Options, clock: &Option<Clock>, player: &Marker) -> Option<Budget> {
+    match *clock {
+        Some(ref clock) => Some(Budget::Time(clock.find_budget(player))),
+        None => options.search_budget,
     }
 }
 
-fn select_a_space(board: &Board, players: &[Players]) -> i32 {
-    let current_player_marker = game::find_current_player(board);
-    let mut space = INVALID_VALUE;
+fn give_hint<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    budget: Option<Budget>,
+    threads: usize,
+) -> String {
+    let scores = computer::score_candidates(rules, board, budget, threads);
+    let (next_move, score) = computer::find_highest_score(&scores);
+    let evaluation = computer::evaluate(&score);
+    let player = rules.find_current_player(board);
+    if rules.chooses_markers() {
+        io::suggest_move(&next_move, &player, &evaluation)
+    } else {
+        io::suggest_space(next_move.space, &player, &evaluation)
+    }
+}
+
+fn end_of_game<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    outcome: &Outcome,
+    options: &Options,
+    number: usize,
+    progress: &Progress,
+) {
+    match options.format {
+        Format::Text => {
+            clear_screen();
+            display(&io::alert_outcome(outcome));
+            display(&rules.format_board(board));
+            if !progress.explanation.is_empty() {
+                display(&progress.explanation);
+            }
+            show_hints_used(&progress.hints);
+        }
+        Format::Json => display(&report::format_game_json(rules, number, board, outcome)),
+    }
+}
+
+fn keep_playing(options: &Options, games_played: usize) -> bool {
+    match options.games {
+        Some(games) => games_played < games as usize,
+        None => io::ask_play_again() == YES,
+    }
+}
+
+fn show_hints_used(hints: &[Marker]) {
+    for player in &[Marker::X, Marker::O] {
+        let used = hints.iter().filter(|hint| hint == &player).count();
+        if used > 0 {
+            display(&io::count_hints(player, used));
+        }
+    }
+}
+
+fn find_player<'a>(players: &'a [Players], marker: &Marker) -> &'a Players {
     players
         .iter()
-        .filter(|player| {
-            marker::inspect(players::get_marker(player)) == marker::inspect(&current_player_marker)
-        })
-        .for_each(|player| space = players::choose_space(player, board));
-    space
+        .find(|player| marker::inspect(players::get_marker(player)) == marker::inspect(marker))
+        .expect("No player for the current marker")
 }
 
-fn show_user_state_of_game(board: &Board) {
-    let current_player_marker = game::find_current_player(board);
+fn show_user_state_of_game<R: Rules + ?Sized>(rules: &R, board: &Board, progress: &Progress) {
+    let current_player_marker = rules.find_current_player(board);
     clear_screen();
     display(&io::select_space(&current_player_marker));
-    display(&format_board(board));
+    display(&rules.format_board(board));
+    let details = format_details(progress);
+    if !details.is_empty() {
+        display(&details);
+    }
+}
+
+fn format_details(progress: &Progress) -> String {
+    let mut details: Vec<String> = progress.clock.iter().map(Clock::format).collect();
+    if !progress.explanation.is_empty() {
+        details.push(progress.explanation.clone());
+    }
+    details.join("\n")
 }
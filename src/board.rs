@@ -1,14 +1,48 @@
+use rules;
+use symmetry;
+use zobrist;
+use board_formatter;
+use marker::Marker;
+use rules::Rules;
+use variant::Variant;
+use symmetry::{Symmetry, SYMMETRIES};
+use std::hash::{Hash, Hasher};
+
+const FLAT: i32 = 2;
+const SINGLE_BOARD: i32 = 1;
+const EMPTY_KEY: u64 = 0;
+
 pub fn build_board(size: i32) -> Board {
     Board {
         size,
+        dimensions: FLAT,
+        boards: SINGLE_BOARD,
+        win_length: size,
+        first_player: Marker::X,
+        variant: Variant::Standard,
         spaces: Vec::new(),
+        markers: Vec::new(),
+        zobrist_key: EMPTY_KEY,
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Move {
+    pub space: i32,
+    pub marker: Marker,
+}
+
 #[derive(Clone, Default)]
 pub struct Board {
     size: i32,
+    dimensions: i32,
+    boards: i32,
+    win_length: i32,
+    first_player: Marker,
+    variant: Variant,
     spaces: Vec<i32>,
+    markers: Vec<Marker>,
+    zobrist_key: u64,
 }
 
 impl Board {
@@ -16,52 +50,229 @@ impl Board {
         &self.size
     }
 
+    pub fn get_dimensions(&self) -> &i32 {
+        &self.dimensions
+    }
+
+    pub fn get_boards(&self) -> &i32 {
+        &self.boards
+    }
+
+    pub fn get_number_of_spaces(&self) -> i32 {
+        self.boards * self.size.pow(self.dimensions as u32)
+    }
+
+    pub fn get_win_length(&self) -> &i32 {
+        &self.win_length
+    }
+
+    pub fn get_first_player(&self) -> &Marker {
+        &self.first_player
+    }
+
+    pub fn get_variant(&self) -> &Variant {
+        &self.variant
+    }
+
+    pub fn with_dimensions(self, dimensions: i32) -> Board {
+        Board { dimensions, ..self }
+    }
+
+    pub fn with_boards(self, boards: i32) -> Board {
+        Board { boards, ..self }
+    }
+
+    pub fn with_win_length(self, win_length: i32) -> Board {
+        Board { win_length, ..self }
+    }
+
+    pub fn with_first_player(self, first_player: Marker) -> Board {
+        Board {
+            first_player,
+            ..self
+        }
+    }
+
+    pub fn with_variant(self, variant: Variant) -> Board {
+        Board { variant, ..self }
+    }
+
+    pub fn with_moves(self, moves: Vec<Move>) -> Board {
+        Board {
+            spaces: moves.iter().map(|next_move| next_move.space).collect(),
+            markers: moves.iter().map(|next_move| next_move.marker).collect(),
+            zobrist_key: moves.iter().fold(EMPTY_KEY, |key, next_move| {
+                key ^ zobrist::find_key(next_move.space, &next_move.marker)
+            }),
+            ..self
+        }
+    }
+
     pub fn get_spaces(&self) -> &Vec<i32> {
         &self.spaces
     }
 
-    pub fn place_marker(self, space: i32) -> Board {
-        if self.is_move_valid(&space) {
-            self.create_next_board(space)
-        } else {
-            self
+    pub fn get_markers(&self) -> &Vec<Marker> {
+        &self.markers
+    }
+
+    pub fn get_zobrist_key(&self) -> u64 {
+        match self.find_last_move_in_play() {
+            Some(space) => self.zobrist_key ^ zobrist::find_last_move_key(*space),
+            None => self.zobrist_key,
         }
     }
 
-    fn is_move_valid(&self, space: &i32) -> bool {
-        self.is_space_available(space) && self.is_space_in_bounds(space)
+    pub fn place_marker<R: Rules + ?Sized>(self, rules: &R, space: i32) -> Board {
+        let marker = rules.find_default_marker(&self);
+        self.play(rules, Move { space, marker })
+    }
+
+    pub fn play<R: Rules + ?Sized>(self, rules: &R, next_move: Move) -> Board {
+        if rules.is_move_legal(&self, &next_move) {
+            self.create_next_board(next_move)
+        } else {
+            self
+        }
     }
 
     pub fn is_space_available(&self, space: &i32) -> bool {
         !self.spaces.contains(space)
     }
 
-    fn is_space_in_bounds(&self, space: &i32) -> bool {
-        let max_space = self.size * self.size;
-        let min_space = 0;
-        space >= &min_space && space < &max_space
+    pub fn get_symmetries(&self) -> Vec<Symmetry> {
+        if self.dimensions == FLAT {
+            SYMMETRIES.to_vec()
+        } else {
+            vec![Symmetry::Identity]
+        }
     }
 
-    pub fn get_available_spaces(&self) -> Vec<i32> {
-        let all_spaces = 0..self.size * self.size;
-        all_spaces
-            .filter(|space| self.is_space_available(space))
+    pub fn transform(&self, symmetry: Symmetry) -> Board {
+        let moves = self
+            .spaces
+            .iter()
+            .zip(&self.markers)
+            .map(|(space, marker)| Move {
+                space: self.map_space(symmetry::transform_space, symmetry, *space),
+                marker: *marker,
+            })
+            .collect();
+        self.clone().with_moves(moves)
+    }
+
+    pub fn find_transforms(&self) -> Vec<(Symmetry, Board)> {
+        self.get_symmetries()
+            .into_iter()
+            .map(|symmetry| (symmetry, self.transform(symmetry)))
             .collect()
     }
 
-    fn create_next_board(self, space: i32) -> Board {
+    pub fn find_canonical_form(&self) -> (Symmetry, Board) {
+        self.find_transforms()
+            .into_iter()
+            .min_by_key(|(_, board)| board_formatter::expand_board(board))
+            .expect("Every board has an identity transform")
+    }
+
+    pub fn find_canonical_key(&self) -> u64 {
+        self.get_symmetries()
+            .into_iter()
+            .map(|symmetry| self.find_transformed_key(symmetry))
+            .min()
+            .expect("Every board has an identity transform")
+    }
+
+    fn find_transformed_key(&self, symmetry: Symmetry) -> u64 {
+        let key = self
+            .spaces
+            .iter()
+            .zip(&self.markers)
+            .fold(EMPTY_KEY, |key, (space, marker)| {
+                let space = self.map_space(symmetry::transform_space, symmetry, *space);
+                key ^ zobrist::find_key(space, marker)
+            });
+        match self.find_last_move_in_play() {
+            Some(space) => {
+                let space = self.map_space(symmetry::transform_space, symmetry, *space);
+                key ^ zobrist::find_last_move_key(space)
+            }
+            None => key,
+        }
+    }
+
+    pub fn restore_move(&self, symmetry: Symmetry, next_move: Move) -> Move {
+        Move {
+            space: self.map_space(symmetry::restore_space, symmetry, next_move.space),
+            ..next_move
+        }
+    }
+
+    fn map_space(&self, map: fn(Symmetry, i32, i32) -> i32, symmetry: Symmetry, space: i32) -> i32 {
+        let spaces_per_board = self.size * self.size;
+        space - space % spaces_per_board + map(symmetry, space % spaces_per_board, self.size)
+    }
+
+    pub fn undo(mut self) -> Board {
+        if let (Some(space), Some(marker)) = (self.spaces.pop(), self.markers.pop()) {
+            self.zobrist_key ^= zobrist::find_key(space, &marker);
+        }
+        self
+    }
+
+    fn find_last_move_in_play(&self) -> Option<&i32> {
+        if rules::find_rules(self).uses_last_move() {
+            self.spaces.last()
+        } else {
+            None
+        }
+    }
+
+    fn create_next_board(self, next_move: Move) -> Board {
         let mut updated_spaces = self.spaces;
-        updated_spaces.push(space);
+        let mut updated_markers = self.markers;
+        updated_spaces.push(next_move.space);
+        updated_markers.push(next_move.marker);
         Board {
-            size: self.size,
             spaces: updated_spaces,
+            markers: updated_markers,
+            zobrist_key: self.zobrist_key ^ zobrist::find_key(next_move.space, &next_move.marker),
+            ..self
         }
     }
 }
 
+impl PartialEq for Board {
+    fn eq(&self, other: &Board) -> bool {
+        self.get_zobrist_key() == other.get_zobrist_key()
+            && self.size == other.size
+            && self.dimensions == other.dimensions
+            && self.boards == other.boards
+            && self.win_length == other.win_length
+            && self.first_player == other.first_player
+            && self.variant == other.variant
+            && self.find_last_move_in_play() == other.find_last_move_in_play()
+            && board_formatter::expand_board(self) == board_formatter::expand_board(other)
+    }
+}
+
+impl Eq for Board {}
+
+impl Hash for Board {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.get_zobrist_key());
+    }
+}
+
 pub mod tests {
     #[cfg(test)]
     use super::*;
+    #[cfg(test)]
+    use std::collections::HashSet;
+    #[cfg(test)]
+    use ultimate;
+    #[cfg(test)]
+    use rules::{NumericalRules, StandardRules, WildRules};
 
     #[test]
     fn takes_a_number_of_rows() {
@@ -69,6 +280,64 @@ pub mod tests {
         assert_eq!(&3, board.get_size());
     }
 
+    #[test]
+    fn needs_a_full_row_to_win_by_default() {
+        let board = build_board(4);
+        assert_eq!(&4, board.get_win_length());
+    }
+
+    #[test]
+    fn takes_a_win_length() {
+        let board = build_board(5).with_win_length(4);
+        assert_eq!(&4, board.get_win_length());
+    }
+
+    #[test]
+    fn is_flat_by_default() {
+        let board = build_board(3);
+        assert_eq!(&2, board.get_dimensions());
+        assert_eq!(9, board.get_number_of_spaces());
+    }
+
+    #[test]
+    fn takes_a_number_of_dimensions() {
+        let board = build_board(4).with_dimensions(3);
+        assert_eq!(64, board.get_number_of_spaces());
+        assert_eq!(64, StandardRules.find_playable_spaces(&board).len());
+        assert_eq!(&vec![63], board.place_marker(&StandardRules, 63).get_spaces());
+    }
+
+    #[test]
+    fn takes_a_number_of_boards() {
+        let board = build_board(3).with_boards(2);
+        assert_eq!(&2, board.get_boards());
+        assert_eq!(18, board.get_number_of_spaces());
+    }
+
+    #[test]
+    fn x_is_the_first_player_by_default() {
+        let board = build_board(3);
+        assert_eq!(&Marker::X, board.get_first_player());
+    }
+
+    #[test]
+    fn takes_a_first_player() {
+        let board = build_board(3).with_first_player(Marker::O);
+        assert_eq!(&Marker::O, board.get_first_player());
+    }
+
+    #[test]
+    fn plays_the_standard_game_by_default() {
+        let board = build_board(3);
+        assert_eq!(&Variant::Standard, board.get_variant());
+    }
+
+    #[test]
+    fn takes_a_variant() {
+        let board = build_board(3).with_variant(Variant::Misere);
+        assert_eq!(&Variant::Misere, board.get_variant());
+    }
+
     #[test]
     fn starts_with_no_moves() {
         let spaces: Vec<i32> = vec![];
@@ -115,28 +384,279 @@ pub mod tests {
     fn finds_available_spaces_in_empty_board() {
         let board = set_up_board(3, vec![]);
         let available_spaces = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
-        assert_eq!(available_spaces, board.get_available_spaces());
+        assert_eq!(available_spaces, StandardRules.find_playable_spaces(&board));
     }
 
     #[test]
     fn finds_available_spaces_in_full_board() {
         let board = set_up_board(3, vec![0, 4, 8, 2, 6, 7, 1, 3, 5]);
         let available_spaces: Vec<i32> = vec![];
-        assert_eq!(available_spaces, board.get_available_spaces());
+        assert_eq!(available_spaces, StandardRules.find_playable_spaces(&board));
     }
 
     #[test]
     fn finds_available_spaces_in_an_in_progress_board() {
         let board = set_up_board(3, vec![0, 4, 8, 2, 6]);
         let available_spaces: Vec<i32> = vec![1, 3, 5, 7];
-        assert_eq!(available_spaces, board.get_available_spaces());
+        assert_eq!(available_spaces, StandardRules.find_playable_spaces(&board));
+    }
+
+    #[test]
+    fn records_the_marker_of_each_move() {
+        let board = set_up_board(3, vec![0, 4]);
+        assert_eq!(&vec![Marker::X, Marker::O], board.get_markers());
+    }
+
+    #[test]
+    fn a_player_cant_place_the_other_players_marker() {
+        let board = set_up_board(3, vec![0]).play(&StandardRules, Move {
+            space: 4,
+            marker: Marker::X,
+        });
+        assert_eq!(&vec![0], board.get_spaces());
+    }
+
+    #[test]
+    fn a_player_can_place_either_marker_in_wild() {
+        let board = build_board(3)
+            .with_variant(Variant::Wild)
+            .play(&WildRules, Move {
+                space: 0,
+                marker: Marker::O,
+            })
+            .play(&WildRules, Move {
+                space: 4,
+                marker: Marker::O,
+            });
+        assert_eq!(&vec![Marker::O, Marker::O], board.get_markers());
+    }
+
+    #[test]
+    fn plays_by_the_rules_it_is_given() {
+        let next_move = Move {
+            space: 0,
+            marker: Marker::O,
+        };
+        assert!(build_board(3).play(&StandardRules, next_move).get_spaces().is_empty());
+        assert_eq!(&vec![0], build_board(3).play(&WildRules, next_move).get_spaces());
+    }
+
+    #[test]
+    fn finds_the_moves_of_the_current_player() {
+        let board = set_up_board(3, vec![0, 1, 2, 3, 4, 5, 6]);
+        let moves = vec![
+            Move {
+                space: 7,
+                marker: Marker::O,
+            },
+            Move {
+                space: 8,
+                marker: Marker::O,
+            },
+        ];
+        assert_eq!(moves, StandardRules.find_legal_moves(&board));
+    }
+
+    #[test]
+    fn finds_moves_with_either_marker_in_wild() {
+        let board = set_up_board(3, vec![0, 1, 2, 3, 4, 5, 6, 7]).with_variant(Variant::Wild);
+        let moves = vec![
+            Move {
+                space: 8,
+                marker: Marker::X,
+            },
+            Move {
+                space: 8,
+                marker: Marker::O,
+            },
+        ];
+        assert_eq!(moves, WildRules.find_legal_moves(&board));
+    }
+
+    #[test]
+    fn the_first_player_places_odd_numbers_in_numerical() {
+        let board = build_board(3).with_variant(Variant::Numerical);
+        assert_eq!(
+            vec![
+                Marker::Number(1),
+                Marker::Number(3),
+                Marker::Number(5),
+                Marker::Number(7),
+                Marker::Number(9),
+            ],
+            NumericalRules.find_playable_markers(&board)
+        );
+        let board = board.place_marker(&NumericalRules, 4);
+        assert!(board.get_spaces().is_empty());
+    }
+
+    #[test]
+    fn each_number_is_placed_once_in_numerical() {
+        let board = build_board(3)
+            .with_variant(Variant::Numerical)
+            .play(&NumericalRules, Move {
+                space: 0,
+                marker: Marker::Number(5),
+            })
+            .play(&NumericalRules, Move {
+                space: 1,
+                marker: Marker::Number(3),
+            })
+            .play(&NumericalRules, Move {
+                space: 1,
+                marker: Marker::Number(4),
+            });
+        assert_eq!(&vec![Marker::Number(5), Marker::Number(4)], board.get_markers());
+        assert_eq!(
+            vec![
+                Marker::Number(1),
+                Marker::Number(3),
+                Marker::Number(7),
+                Marker::Number(9),
+            ],
+            NumericalRules.find_playable_markers(&board)
+        );
+        assert_eq!(7 * 4, NumericalRules.find_legal_moves(&board).len());
+    }
+
+    #[test]
+    fn has_eight_symmetries_on_a_flat_board() {
+        assert_eq!(8, build_board(3).get_symmetries().len());
+        assert_eq!(
+            vec![Symmetry::Identity],
+            build_board(3).with_dimensions(3).get_symmetries()
+        );
+    }
+
+    #[test]
+    fn transforms_every_move_and_keeps_the_markers() {
+        let board = set_up_board(3, vec![0, 4, 5]).transform(Symmetry::RotateRight);
+        assert_eq!(&vec![2, 4, 7], board.get_spaces());
+        assert_eq!(&vec![Marker::X, Marker::O, Marker::X], board.get_markers());
+    }
+
+    #[test]
+    fn finds_a_transform_for_each_symmetry() {
+        let transforms = set_up_board(3, vec![1]).find_transforms();
+        let mut spaces: Vec<i32> = transforms
+            .iter()
+            .map(|(_, board)| board.get_spaces()[0])
+            .collect();
+        spaces.sort();
+        assert_eq!(vec![1, 1, 3, 3, 5, 5, 7, 7], spaces);
+    }
+
+    #[test]
+    fn every_orientation_has_the_same_canonical_form() {
+        let board = set_up_board(3, vec![0, 4, 5]);
+        let canonical = board_formatter::expand_board(&board.find_canonical_form().1);
+        for (_, transform) in board.find_transforms() {
+            let (_, transform_canonical) = transform.find_canonical_form();
+            assert_eq!(canonical, board_formatter::expand_board(&transform_canonical));
+        }
+    }
+
+    #[test]
+    fn maps_a_move_back_from_the_canonical_form() {
+        let board = set_up_board(3, vec![8]);
+        let (symmetry, canonical) = board.find_canonical_form();
+        let next_move = Move {
+            space: canonical.get_spaces()[0],
+            marker: Marker::X,
+        };
+        assert_eq!(8, board.restore_move(symmetry, next_move).space);
+    }
+
+    #[test]
+    fn transforms_each_board_in_a_row_of_boards() {
+        let board = build_board(3).with_boards(2).place_marker(&StandardRules, 9);
+        assert_eq!(&vec![11], board.transform(Symmetry::FlipHorizontal).get_spaces());
+    }
+
+    #[test]
+    fn positions_that_are_the_same_up_to_symmetry_share_a_canonical_key() {
+        let board = set_up_board(3, vec![0, 4, 5]);
+        let rotated = board.transform(Symmetry::RotateRight);
+        assert_eq!(board.find_canonical_key(), rotated.find_canonical_key());
+        assert_ne!(
+            board.find_canonical_key(),
+            set_up_board(3, vec![0, 4, 1]).find_canonical_key()
+        );
+    }
+
+    #[test]
+    fn the_same_position_has_the_same_key_whatever_the_move_order() {
+        let board = set_up_board(3, vec![0, 4, 8, 2]);
+        let transposed = set_up_board(3, vec![8, 2, 0, 4]);
+        assert_eq!(board.get_zobrist_key(), transposed.get_zobrist_key());
+        assert!(board == transposed);
+    }
+
+    #[test]
+    fn different_positions_have_different_keys() {
+        let board = set_up_board(3, vec![0, 4]);
+        assert_ne!(board.get_zobrist_key(), set_up_board(3, vec![4, 0]).get_zobrist_key());
+        assert_ne!(board.get_zobrist_key(), set_up_board(3, vec![0]).get_zobrist_key());
+        assert!(board != set_up_board(3, vec![0, 4]).with_win_length(2));
+    }
+
+    #[test]
+    fn keeps_the_key_up_to_date_as_moves_are_played() {
+        let board = set_up_board(3, vec![0, 4, 8]);
+        let moves = vec![
+            Move {
+                space: 0,
+                marker: Marker::X,
+            },
+            Move {
+                space: 4,
+                marker: Marker::O,
+            },
+            Move {
+                space: 8,
+                marker: Marker::X,
+            },
+        ];
+        let rebuilt = build_board(3).with_moves(moves);
+        assert_eq!(rebuilt.get_zobrist_key(), board.get_zobrist_key());
+        let identity = board.transform(Symmetry::Identity);
+        assert_eq!(rebuilt.get_zobrist_key(), identity.get_zobrist_key());
+    }
+
+    #[test]
+    fn undoes_the_last_move() {
+        let board = set_up_board(3, vec![0, 4, 8]).undo();
+        assert_eq!(&vec![0, 4], board.get_spaces());
+        assert_eq!(&vec![Marker::X, Marker::O], board.get_markers());
+        assert_eq!(set_up_board(3, vec![0, 4]).get_zobrist_key(), board.get_zobrist_key());
+        assert!(board.undo().undo().undo() == build_board(3));
+    }
+
+    #[test]
+    fn counts_each_position_once_in_a_set() {
+        let positions: HashSet<Board> = vec![
+            set_up_board(3, vec![0, 4, 8, 2]),
+            set_up_board(3, vec![8, 2, 0, 4]),
+            set_up_board(3, vec![8, 4, 0, 2]),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(1, positions.len());
+    }
+
+    #[test]
+    fn the_last_move_is_part_of_an_ultimate_position() {
+        let board = ultimate::tests::set_up_ultimate_board(vec![0, 1, 9]);
+        let transposed = ultimate::tests::set_up_ultimate_board(vec![9, 1, 0]);
+        assert_ne!(board.get_zobrist_key(), transposed.get_zobrist_key());
+        assert!(board != transposed);
     }
 
     #[cfg(test)]
     pub fn set_up_board(size: i32, spaces: Vec<i32>) -> Board {
         let mut board: Board = build_board(size);
         for space in spaces {
-            board = board.place_marker(space);
+            board = board.place_marker(&StandardRules, space);
         }
         board
     }
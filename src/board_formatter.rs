@@ -1,34 +1,26 @@
 use board::Board;
-use marker::Marker;
 use marker;
 
 pub fn expand_board(board: &Board) -> Vec<String> {
-    let spaces = board.get_spaces();
-    let number_of_spaces = board.get_size() * board.get_size();
+    let number_of_spaces = board.get_number_of_spaces();
     let mut expanded_board: Vec<String> = vec![" ".to_string(); number_of_spaces as usize];
-    for (index, space) in spaces.iter().enumerate() {
-        expanded_board[*space as usize] = marker::inspect(&set_marker(index));
+    for (space, marker) in board.get_spaces().iter().zip(board.get_markers()) {
+        expanded_board[*space as usize] = marker::inspect(marker);
     }
     expanded_board
 }
 
-fn set_marker(index: usize) -> Marker {
-    if is_even(index) {
-        Marker::X
-    } else {
-        Marker::O
-    }
-}
-
-fn is_even(index: usize) -> bool {
-    index % 2 == 0
-}
-
 pub mod tests {
     #[cfg(test)]
     use super::*;
     #[cfg(test)]
+    use board::build_board;
+    #[cfg(test)]
+    use marker::Marker;
+    #[cfg(test)]
     use board::tests::set_up_board;
+    #[cfg(test)]
+    use rules::StandardRules;
     #[test]
     fn convert_empty_board() {
         let board = set_up_board(3, vec![]);
@@ -79,4 +71,14 @@ pub mod tests {
         ];
         assert_eq!(expanded_board, expand_board(&board));
     }
+
+    #[test]
+    fn convert_board_where_o_plays_first() {
+        let board = build_board(3)
+            .with_first_player(Marker::O)
+            .place_marker(&StandardRules, 0)
+            .place_marker(&StandardRules, 4);
+        assert_eq!("O", expand_board(&board)[0]);
+        assert_eq!("X", expand_board(&board)[4]);
+    }
 }
@@ -1,49 +1,211 @@
 use board_formatter;
 use lines;
+use ultimate;
 use board::Board;
+use rules::Rules;
 
 const OFFSET: usize = 1;
+const SEPARATOR: char = '|';
+const DIVIDER: char = '-';
+const SUB_BOARD_SEPARATOR: char = '#';
+const SUB_BOARD_DIVIDER: char = '=';
+pub const LAYER: &str = "Layer ";
+pub const BOARD: &str = "Board ";
+const SINGLE_GRID: i32 = 1;
+const LAYER_GAP: &str = "   ";
+const LAYER_HEADER: usize = 1;
 
-pub fn format_board(board: &Board) -> String {
-    let split_board = lines::split_board_into_rows(
-        &number_spaces(&board_formatter::expand_board(board)),
-        board.get_size().abs(),
-    );
+#[derive(PartialEq, Debug)]
+pub struct Span {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+pub fn format_board<R: Rules + ?Sized>(rules: &R, board: &Board) -> String {
+    if count_grids(board) > SINGLE_GRID {
+        return format_layers(rules, board);
+    }
+    format_grid(&number_spaces(rules, board), *board.get_size())
+}
+
+fn expand_values<R: Rules + ?Sized>(rules: &R, board: &Board) -> Vec<String> {
+    board_formatter::expand_board(board)
+        .iter()
+        .map(|space| match space.as_str() {
+            " " => space.to_string(),
+            value => rules.format_value(value),
+        })
+        .collect()
+}
+
+fn format_grid(spaces: &[String], size: i32) -> String {
+    let split_board = lines::split_board_into_rows(spaces, size.abs());
     let mut formatted_board: String = "".to_string();
     for (index, row) in split_board.iter().enumerate() {
         let formatted_row = format_row(&row.to_vec());
         let length = formatted_row.len();
         formatted_board += &formatted_row;
         if index < row.len() - OFFSET {
-            formatted_board += &"-".repeat(length - OFFSET);
+            formatted_board += &DIVIDER.to_string().repeat(length - OFFSET);
             formatted_board += "\n";
         }
     }
     formatted_board
 }
 
+fn count_grids(board: &Board) -> i32 {
+    board.get_number_of_spaces() / (board.get_size() * board.get_size())
+}
+
+fn format_layers<R: Rules + ?Sized>(rules: &R, board: &Board) -> String {
+    let layers = format_layer_grids(rules, board);
+    let label = rules.find_grid_label();
+    let mut formatted_board = layers
+        .iter()
+        .enumerate()
+        .map(|(index, layer)| {
+            format!(
+                "{:width$}",
+                format!("{}{}", label, index + OFFSET),
+                width = find_width(layer)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(LAYER_GAP);
+    formatted_board = formatted_board.trim_end().to_string() + "\n";
+    let rows: Vec<Vec<&str>> = layers.iter().map(|layer| layer.lines().collect()).collect();
+    for row in 0..rows[0].len() {
+        let line: Vec<&str> = rows.iter().map(|layer| layer[row]).collect();
+        formatted_board += &line.join(LAYER_GAP);
+        formatted_board += "\n";
+    }
+    formatted_board
+}
+
+fn format_layer_grids<R: Rules + ?Sized>(rules: &R, board: &Board) -> Vec<String> {
+    let size = *board.get_size();
+    number_spaces(rules, board)
+        .chunks((size * size) as usize)
+        .map(|layer| format_grid(layer, size))
+        .collect()
+}
+
+fn find_width(grid: &str) -> usize {
+    grid.lines().map(|line| line.len()).max().unwrap_or(0)
+}
+
+pub fn format_ultimate_board<R: Rules + ?Sized>(rules: &R, board: &Board) -> String {
+    let spaces = number_playable_spaces(
+        &board_formatter::expand_board(board),
+        &rules.find_playable_spaces(board),
+    );
+    let sub_board_size = ultimate::SUB_BOARD_SIZE as usize;
+    let split_board = lines::split_board_into_rows(&spaces, ultimate::ULTIMATE_SIZE);
+    let mut formatted_board: String = "".to_string();
+    for (index, row) in split_board.iter().enumerate() {
+        let formatted_row = format_ultimate_row(row);
+        let length = formatted_row.len();
+        formatted_board += &formatted_row;
+        if index < row.len() - OFFSET {
+            let divider = if (index + OFFSET) % sub_board_size == 0 {
+                SUB_BOARD_DIVIDER
+            } else {
+                DIVIDER
+            };
+            formatted_board += &divider.to_string().repeat(length - OFFSET);
+            formatted_board += "\n";
+        }
+    }
+    formatted_board
+}
+
+fn format_ultimate_row(row: &[String]) -> String {
+    let sub_board_rows: Vec<String> = row
+        .chunks(ultimate::SUB_BOARD_SIZE as usize)
+        .map(|cells| format_row(cells).trim_end_matches('\n').to_string())
+        .collect();
+    sub_board_rows.join(&SUB_BOARD_SEPARATOR.to_string()) + "\n"
+}
+
+pub fn locate_spaces<R: Rules + ?Sized>(rules: &R, board: &Board) -> Vec<Span> {
+    if count_grids(board) > SINGLE_GRID {
+        return locate_layer_spaces(rules, board);
+    }
+    locate_grid_spaces(&rules.format_board(board))
+}
+
+fn locate_layer_spaces<R: Rules + ?Sized>(rules: &R, board: &Board) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+    let mut left = 0;
+    for layer in format_layer_grids(rules, board) {
+        spans.extend(locate_grid_spaces(&layer).iter().map(|span| Span {
+            line: span.line + LAYER_HEADER,
+            start: span.start + left,
+            end: span.end + left,
+        }));
+        left += find_width(&layer) + LAYER_GAP.len();
+    }
+    spans
+}
+
+fn locate_grid_spaces(formatted_board: &str) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+    for (line, text) in formatted_board.lines().enumerate() {
+        if text
+            .chars()
+            .all(|character| character == DIVIDER || character == SUB_BOARD_DIVIDER)
+        {
+            continue;
+        }
+        let mut start = 0;
+        for cell in text.split(&[SEPARATOR, SUB_BOARD_SEPARATOR][..]) {
+            let end = start + cell.len();
+            spans.push(Span { line, start, end });
+            start = end + OFFSET;
+        }
+    }
+    spans
+}
+
+pub fn find_space_at<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    column: usize,
+    line: usize,
+) -> Option<i32> {
+    locate_spaces(rules, board)
+        .iter()
+        .position(|span| span.line == line && column >= span.start && column < span.end)
+        .map(|space| space as i32)
+}
+
 fn format_row(row: &[String]) -> String {
     let mut formatted_row: String = "".to_string();
     for (index, mark) in row.iter().enumerate() {
-        formatted_row.push_str(" ");
+        formatted_row.push(' ');
         formatted_row.push_str(mark);
         if mark.len() == OFFSET {
-            formatted_row.push_str(" ");
+            formatted_row.push(' ');
         }
-        formatted_row.push_str(" ");
+        formatted_row.push(' ');
         if index < row.len() - OFFSET {
-            formatted_row.push_str("|");
+            formatted_row.push(SEPARATOR);
         } else {
-            formatted_row.push_str("\n");
+            formatted_row.push('\n');
         }
     }
     formatted_row
 }
 
-fn number_spaces(spaces: &[String]) -> Vec<String> {
-    let mut updated_spaces: Vec<String> = vec![" ".to_string(); spaces.len() as usize];
+fn number_spaces<R: Rules + ?Sized>(rules: &R, board: &Board) -> Vec<String> {
+    number_playable_spaces(&expand_values(rules, board), &rules.find_playable_spaces(board))
+}
+
+fn number_playable_spaces(spaces: &[String], playable_spaces: &[i32]) -> Vec<String> {
+    let mut updated_spaces: Vec<String> = vec![" ".to_string(); spaces.len()];
     for (index, space) in spaces.iter().enumerate() {
-        if space == " " {
+        if space == " " && playable_spaces.contains(&(index as i32)) {
             let number = index + OFFSET;
             updated_spaces[index] = number.to_string();
         } else {
@@ -57,13 +219,19 @@ fn number_spaces(spaces: &[String]) -> Vec<String> {
 mod tests {
     use super::*;
     use board::tests::set_up_board;
+    use marker::Marker;
+    use board::Move;
+    use notakto;
+    use ultimate::UltimateRules;
+    use rules::{find_rules, NumericalRules, StandardRules};
+    use variant::Variant;
     #[test]
     fn displays_an_empty_3_by_3_board() {
         let board: Board = set_up_board(3, vec![]);
         let blank_board: String =
             " 1  | 2  | 3  \n--------------\n 4  | 5  | 6  \n--------------\n 7  | 8  | 9  \n"
                 .to_string();
-        assert_eq!(blank_board, format_board(&board));
+        assert_eq!(blank_board, find_rules(&board).format_board(&board));
     }
 
     #[test]
@@ -72,7 +240,138 @@ mod tests {
         let blank_board: String =
             " X  | X  | O  \n--------------\n O  | O  | X  \n--------------\n X  | O  | X  \n"
                 .to_string();
-        assert_eq!(blank_board, format_board(&board));
+        assert_eq!(blank_board, find_rules(&board).format_board(&board));
+    }
+
+    #[test]
+    fn locates_the_spaces_of_a_3_by_3_board() {
+        let board: Board = set_up_board(3, vec![]);
+        let spans = locate_spaces(find_rules(&board), &board);
+        assert_eq!(9, spans.len());
+        assert_eq!(Span { line: 0, start: 0, end: 4 }, spans[0]);
+        assert_eq!(Span { line: 2, start: 5, end: 9 }, spans[4]);
+        assert_eq!(Span { line: 4, start: 10, end: 14 }, spans[8]);
+    }
+
+    #[test]
+    fn locates_the_spaces_of_a_4_by_4_board() {
+        let board: Board = set_up_board(4, vec![]);
+        let spans = locate_spaces(find_rules(&board), &board);
+        assert_eq!(16, spans.len());
+        assert_eq!(Span { line: 6, start: 15, end: 19 }, spans[15]);
+    }
+
+    #[test]
+    fn finds_the_space_at_a_position_on_the_board() {
+        let board: Board = set_up_board(3, vec![]);
+        assert_eq!(Some(0), find_space_at(find_rules(&board), &board, 0, 0));
+        assert_eq!(Some(4), find_space_at(find_rules(&board), &board, 7, 2));
+        assert_eq!(Some(8), find_space_at(find_rules(&board), &board, 13, 4));
+    }
+
+    #[test]
+    fn finds_no_space_on_a_divider_or_separator() {
+        let board: Board = set_up_board(3, vec![]);
+        assert_eq!(None, find_space_at(find_rules(&board), &board, 4, 0));
+        assert_eq!(None, find_space_at(find_rules(&board), &board, 2, 1));
+        assert_eq!(None, find_space_at(find_rules(&board), &board, 14, 0));
+        assert_eq!(None, find_space_at(find_rules(&board), &board, 0, 5));
+    }
+
+    #[test]
+    fn finds_the_space_at_a_position_on_a_board_with_wide_numbers() {
+        let board: Board = set_up_board(10, vec![]);
+        assert_eq!(Some(98), find_space_at(find_rules(&board), &board, 42, 18));
+        assert_eq!(Some(99), find_space_at(find_rules(&board), &board, 48, 18));
+    }
+
+    #[test]
+    fn displays_the_playable_spaces_of_an_ultimate_board() {
+        let board = ultimate::build_board(Marker::X).place_marker(&UltimateRules, 40);
+        let formatted_board = find_rules(&board).format_board(&board);
+        let rows: Vec<&str> = formatted_board.lines().collect();
+        assert_eq!(17, rows.len());
+        assert_eq!(
+            "    |    |    #    |    |    #    |    |    ",
+            rows[0]
+        );
+        assert_eq!(
+            "    |    |    # 31 | 32 | 33 #    |    |    ",
+            rows[6]
+        );
+        assert_eq!(
+            "    |    |    # 40 | X  | 42 #    |    |    ",
+            rows[8]
+        );
+        assert_eq!("=".repeat(44), rows[5]);
+        assert_eq!("-".repeat(44), rows[7]);
+    }
+
+    #[test]
+    fn locates_the_spaces_of_an_ultimate_board() {
+        let board = ultimate::build_board(Marker::X);
+        let spans = locate_spaces(find_rules(&board), &board);
+        assert_eq!(81, spans.len());
+        assert_eq!(Span { line: 0, start: 15, end: 19 }, spans[3]);
+        assert_eq!(Some(40), find_space_at(find_rules(&board), &board, 22, 8));
+    }
+
+    #[test]
+    fn displays_the_layers_of_a_cube_side_by_side() {
+        let board = set_up_board(4, vec![]).with_dimensions(3).place_marker(&StandardRules, 21);
+        let formatted_board = find_rules(&board).format_board(&board);
+        let rows: Vec<&str> = formatted_board.lines().collect();
+        assert_eq!(8, rows.len());
+        assert_eq!(
+            "Layer 1               Layer 2               Layer 3               Layer 4",
+            rows[0]
+        );
+        assert_eq!(
+            " 1  | 2  | 3  | 4      17 | 18 | 19 | 20     33 | 34 | 35 | 36     \
+             49 | 50 | 51 | 52 ",
+            rows[1]
+        );
+        assert_eq!(
+            " 5  | 6  | 7  | 8      21 | X  | 23 | 24     37 | 38 | 39 | 40     \
+             53 | 54 | 55 | 56 ",
+            rows[3]
+        );
+    }
+
+    #[test]
+    fn displays_notakto_boards_side_by_side_without_the_spaces_of_dead_boards() {
+        let board = notakto::tests::set_up_notakto_board(2, vec![0, 1, 2, 13]);
+        let formatted_board = find_rules(&board).format_board(&board);
+        let rows: Vec<&str> = formatted_board.lines().collect();
+        assert_eq!("Board 1          Board 2", rows[0]);
+        assert_eq!(" X  | X  | X      10 | 11 | 12 ", rows[1]);
+        assert_eq!("    |    |        13 | X  | 15 ", rows[3]);
+    }
+
+    #[test]
+    fn locates_the_spaces_of_a_cube() {
+        let board = set_up_board(4, vec![]).with_dimensions(3);
+        let spans = locate_spaces(find_rules(&board), &board);
+        assert_eq!(64, spans.len());
+        assert_eq!(Span { line: 1, start: 0, end: 4 }, spans[0]);
+        assert_eq!(Span { line: 3, start: 27, end: 31 }, spans[21]);
+        assert_eq!(Some(21), find_space_at(find_rules(&board), &board, 28, 3));
+        assert_eq!(None, find_space_at(find_rules(&board), &board, 20, 3));
+    }
+
+    #[test]
+    fn marks_the_numbers_placed_in_numerical() {
+        let board = set_up_board(3, vec![])
+            .with_variant(Variant::Numerical)
+            .play(&NumericalRules, Move {
+                space: 4,
+                marker: Marker::Number(7),
+            });
+        assert_eq!(
+            " 1  | 2  | 3  \n--------------\n 4  | =7 | 6  \n\
+             --------------\n 7  | 8  | 9  \n",
+            find_rules(&board).format_board(&board)
+        );
     }
 
     #[test]
@@ -99,17 +398,20 @@ mod tests {
         ];
         assert_eq!(
             numbered_spaces,
-            number_spaces(&vec![
-                " ".to_string(),
-                " ".to_string(),
-                " ".to_string(),
-                " ".to_string(),
-                " ".to_string(),
-                " ".to_string(),
-                " ".to_string(),
-                " ".to_string(),
-                " ".to_string(),
-            ])
+            number_playable_spaces(
+                &vec![
+                    " ".to_string(),
+                    " ".to_string(),
+                    " ".to_string(),
+                    " ".to_string(),
+                    " ".to_string(),
+                    " ".to_string(),
+                    " ".to_string(),
+                    " ".to_string(),
+                    " ".to_string(),
+                ],
+                &[0, 1, 2, 3, 4, 5, 6, 7, 8]
+            )
         );
     }
 
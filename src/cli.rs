@@ -0,0 +1,708 @@
+use difficulty;
+use difficulty::Difficulty;
+use marker::Marker;
+use variant;
+use variant::Variant;
+use clock::TimeControl;
+use computer::Budget;
+use mcts;
+use std::slice::Iter;
+use std::thread;
+use std::time::Duration;
+
+const DEFAULT_SIZE: i32 = 3;
+const MINIMUM: i32 = 1;
+const DEFAULT_DIMENSIONS: i32 = 2;
+const MAXIMUM_DIMENSIONS: i32 = 3;
+const DEFAULT_BOARDS: i32 = 3;
+const INCREMENT_SEPARATOR: char = '+';
+const DEFAULT_THREADS: usize = 1;
+
+pub const USAGE: &str = "Usage: tic_tac_toe [options]
+       tic_tac_toe analyze <position> [options]
+       tic_tac_toe perft [<position>] [options]
+       tic_tac_toe solve
+
+A position is a list of moves such as 5,1,9 (5o,1x,9o in wild and
+order-and-chaos, 5=1,1=2 in numerical) or a grid such as X.O/.X./...
+perft counts the positions and results of every game from a position, by
+the number of moves played. solve prints the table of solved 3x3 positions
+the computer plays from.
+
+Options:
+  --mode <mode>          1 or human-human, 2 or human-computer,
+                         3 or computer-human, 4 or computer-computer
+  --size <rows>          number of rows on the board (default 3)
+  --dimensions <2|3>     3 plays on a cube, such as --size 4 for Qubic
+  --win-length <count>   markers in a row needed to win (default: the board size)
+  --variant <rules>      standard, misere where completing a line loses,
+                         wild where each move places X or O, ultimate
+                         where nine boards make up a 9x9 board,
+                         numerical where odd and even numbers add up to 15,
+                         notakto where both players place X, or
+                         order-and-chaos where Order needs five in a row
+  --boards <count>       number of boards in notakto (default 3)
+  --first <x|o>          marker that moves first (default x)
+  --difficulty <level>   easy, medium or hard (default hard)
+  --seed <number>        seed for the computer's random choices
+  --games <count>        number of games to play without asking to play again
+  --move-time <seconds>  time each player has for every move
+  --clock <seconds>[+<increment>]
+                         time each player has for the whole game, with
+                         seconds added after each move, such as 300+2
+  --search-time <ms>     milliseconds the computer searches for each move
+  --search-nodes <count> positions the computer searches for each move
+  --mcts <x|o|both>      computer players that use Monte Carlo tree search
+  --iterations <count>   games simulated for each Monte Carlo move (default 1000)
+  --exploration <number> how much Monte Carlo search tries unexplored moves
+                         (default 1.41)
+  --strategy <x|o|both>  computer players that follow fixed rules instead of searching
+  --threads <count>      threads the computer searches with (default: one per core)
+  --depth <moves>        how many moves deep perft counts (default: every game)
+  --format <text|json>   how each game or analysis is reported (default text)
+  --verbose              show the score of every move the computer considered
+  --help                 show this message";
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Options {
+    pub mode: Option<i32>,
+    pub size: i32,
+    pub dimensions: i32,
+    pub boards: i32,
+    pub win_length: Option<i32>,
+    pub variant: Option<Variant>,
+    pub first: Marker,
+    pub difficulty: Difficulty,
+    pub seed: Option<u64>,
+    pub games: Option<i32>,
+    pub time_control: Option<TimeControl>,
+    pub search_budget: Option<Budget>,
+    pub mcts_players: Vec<Marker>,
+    pub mcts: mcts::Settings,
+    pub strategy_players: Vec<Marker>,
+    pub threads: usize,
+    pub depth: Option<usize>,
+    pub format: Format,
+    pub verbose: bool,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum Command {
+    Play(Options),
+    Analyze(String, Options),
+    Perft(String, Options),
+    Solve,
+    Help,
+}
+
+pub fn default_options() -> Options {
+    Options {
+        mode: None,
+        size: DEFAULT_SIZE,
+        dimensions: DEFAULT_DIMENSIONS,
+        boards: DEFAULT_BOARDS,
+        win_length: None,
+        variant: None,
+        first: Marker::X,
+        difficulty: Difficulty::Hard,
+        seed: None,
+        games: None,
+        time_control: None,
+        search_budget: None,
+        mcts_players: Vec::new(),
+        mcts: mcts::default_settings(),
+        strategy_players: Vec::new(),
+        threads: find_default_threads(),
+        depth: None,
+        format: Format::Text,
+        verbose: false,
+    }
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    match args.first().map(|command| command.as_str()) {
+        Some("analyze") => match args.get(1) {
+            Some(position) if !position.starts_with("--") => {
+                parse_options(&args[2..]).map(|options| match options {
+                    Some(options) => Command::Analyze(position.to_string(), options),
+                    None => Command::Help,
+                })
+            }
+            _ => Err("Missing position to analyze".to_string()),
+        },
+        Some("perft") => {
+            let (position, remaining) = match args.get(1) {
+                Some(position) if !position.starts_with("--") => (position.as_str(), &args[2..]),
+                _ => ("", &args[1..]),
+            };
+            parse_options(remaining).map(|options| match options {
+                Some(options) => Command::Perft(position.to_string(), options),
+                None => Command::Help,
+            })
+        }
+        Some("solve") => match args.get(1) {
+            Some(argument) => Err(format!("Unexpected argument: {}", argument)),
+            None => Ok(Command::Solve),
+        },
+        _ => parse_options(args).map(|options| match options {
+            Some(options) => Command::Play(options),
+            None => Command::Help,
+        }),
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<Option<Options>, String> {
+    let mut options = default_options();
+    let mut remaining = args.iter();
+    while let Some(argument) = remaining.next() {
+        if argument == "--help" || argument == "-h" {
+            return Ok(None);
+        }
+        if argument == "--verbose" {
+            options.verbose = true;
+            continue;
+        }
+        let (name, value) = split_argument(argument, &mut remaining)?;
+        apply_option(&mut options, &name, &value)?;
+    }
+    validate(&options)?;
+    Ok(Some(options))
+}
+
+fn split_argument(argument: &str, remaining: &mut Iter<String>) -> Result<(String, String), String> {
+    if !argument.starts_with("--") {
+        return Err(format!("Unexpected argument: {}", argument));
+    }
+    match argument.find('=') {
+        Some(index) => Ok((
+            argument[..index].to_string(),
+            argument[index + 1..].to_string(),
+        )),
+        None => match remaining.next() {
+            Some(value) => Ok((argument.to_string(), value.to_string())),
+            None => Err(format!("Missing value for {}", argument)),
+        },
+    }
+}
+
+fn apply_option(options: &mut Options, name: &str, value: &str) -> Result<(), String> {
+    match name {
+        "--mode" => options.mode = Some(parse_mode(name, value)?),
+        "--size" => options.size = parse_number(name, value)?,
+        "--dimensions" => options.dimensions = parse_number(name, value)?,
+        "--boards" => options.boards = parse_number(name, value)?,
+        "--win-length" => options.win_length = Some(parse_number(name, value)?),
+        "--variant" => {
+            options.variant = Some(variant::parse(value).ok_or_else(|| invalid_value(name, value))?)
+        }
+        "--first" => options.first = parse_marker(name, value)?,
+        "--difficulty" => {
+            options.difficulty =
+                difficulty::parse(value).ok_or_else(|| invalid_value(name, value))?
+        }
+        "--seed" => {
+            options.seed = Some(
+                value
+                    .parse::<u64>()
+                    .map_err(|_e| invalid_value(name, value))?,
+            )
+        }
+        "--games" => options.games = Some(parse_number(name, value)?),
+        "--move-time" => {
+            options.time_control = Some(TimeControl::PerMove(parse_seconds(name, value)?))
+        }
+        "--clock" => options.time_control = Some(parse_clock(name, value)?),
+        "--search-time" => {
+            let milliseconds = parse_count(name, value)?;
+            options.search_budget = Some(Budget::Time(Duration::from_millis(milliseconds)))
+        }
+        "--search-nodes" => options.search_budget = Some(Budget::Nodes(parse_count(name, value)?)),
+        "--mcts" => options.mcts_players = parse_players(name, value)?,
+        "--iterations" => options.mcts.iterations = parse_count(name, value)?,
+        "--exploration" => options.mcts.exploration = parse_exploration(name, value)?,
+        "--strategy" => options.strategy_players = parse_players(name, value)?,
+        "--threads" => options.threads = parse_count(name, value)? as usize,
+        "--depth" => options.depth = Some(parse_count(name, value)? as usize),
+        "--format" => options.format = parse_format(name, value)?,
+        _ => return Err(format!("Unknown option: {}", name)),
+    }
+    Ok(())
+}
+
+fn validate(options: &Options) -> Result<(), String> {
+    if options.size < MINIMUM {
+        return Err(invalid_value("--size", &options.size.to_string()));
+    }
+    let ignored_options = options.variant.map_or(&[][..], variant::find_ignored_options);
+    for name in ignored_options {
+        if let Some(value) = find_chosen_value(options, name) {
+            return Err(invalid_value(name, &value));
+        }
+    }
+    if options.dimensions < DEFAULT_DIMENSIONS || options.dimensions > MAXIMUM_DIMENSIONS {
+        return Err(invalid_value("--dimensions", &options.dimensions.to_string()));
+    }
+    if options.boards < MINIMUM {
+        return Err(invalid_value("--boards", &options.boards.to_string()));
+    }
+    if let Some(win_length) = options.win_length {
+        if win_length < MINIMUM || win_length > options.size {
+            return Err(invalid_value("--win-length", &win_length.to_string()));
+        }
+    }
+    if options
+        .strategy_players
+        .iter()
+        .any(|marker| options.mcts_players.contains(marker))
+    {
+        return Err("--mcts and --strategy choose the same player".to_string());
+    }
+    if let Some(games) = options.games {
+        if games < MINIMUM {
+            return Err(invalid_value("--games", &games.to_string()));
+        }
+    }
+    Ok(())
+}
+
+fn find_chosen_value(options: &Options, name: &str) -> Option<String> {
+    let value = match name {
+        "--size" if options.size != DEFAULT_SIZE => options.size,
+        "--dimensions" if options.dimensions != DEFAULT_DIMENSIONS => options.dimensions,
+        "--boards" if options.boards != DEFAULT_BOARDS => options.boards,
+        "--win-length" => options.win_length?,
+        _ => return None,
+    };
+    Some(value.to_string())
+}
+
+fn find_default_threads() -> usize {
+    thread::available_parallelism().map_or(DEFAULT_THREADS, |threads| threads.get())
+}
+
+fn parse_number(name: &str, value: &str) -> Result<i32, String> {
+    value.parse::<i32>().map_err(|_e| invalid_value(name, value))
+}
+
+fn parse_seconds(name: &str, value: &str) -> Result<Duration, String> {
+    parse_count(name, value).map(Duration::from_secs)
+}
+
+fn parse_count(name: &str, value: &str) -> Result<u64, String> {
+    match value.parse::<u64>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(invalid_value(name, value)),
+    }
+}
+
+fn parse_clock(name: &str, value: &str) -> Result<TimeControl, String> {
+    let mut parts = value.splitn(2, INCREMENT_SEPARATOR);
+    let total = parse_seconds(name, parts.next().unwrap_or(""))
+        .map_err(|_e| invalid_value(name, value))?;
+    let increment = match parts.next() {
+        Some(increment) => Duration::from_secs(
+            increment
+                .parse::<u64>()
+                .map_err(|_e| invalid_value(name, value))?,
+        ),
+        None => Duration::from_secs(0),
+    };
+    Ok(TimeControl::Game { total, increment })
+}
+
+fn parse_players(name: &str, value: &str) -> Result<Vec<Marker>, String> {
+    match value {
+        "both" => Ok(vec![Marker::X, Marker::O]),
+        _ => parse_marker(name, value).map(|marker| vec![marker]),
+    }
+}
+
+fn parse_exploration(name: &str, value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(exploration) if exploration.is_finite() && exploration >= 0.0 => Ok(exploration),
+        _ => Err(invalid_value(name, value)),
+    }
+}
+
+fn parse_mode(name: &str, value: &str) -> Result<i32, String> {
+    match value {
+        "1" | "human-human" => Ok(1),
+        "2" | "human-computer" => Ok(2),
+        "3" | "computer-human" => Ok(3),
+        "4" | "computer-computer" => Ok(4),
+        _ => Err(invalid_value(name, value)),
+    }
+}
+
+fn parse_marker(name: &str, value: &str) -> Result<Marker, String> {
+    match value {
+        "x" | "X" => Ok(Marker::X),
+        "o" | "O" => Ok(Marker::O),
+        _ => Err(invalid_value(name, value)),
+    }
+}
+
+fn parse_format(name: &str, value: &str) -> Result<Format, String> {
+    match value {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        _ => Err(invalid_value(name, value)),
+    }
+}
+
+fn invalid_value(name: &str, value: &str) -> String {
+    format!("Invalid value for {}: {}", name, value)
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+
+    #[cfg(test)]
+    fn arguments(line: &str) -> Vec<String> {
+        line.split_whitespace().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn uses_the_defaults_without_arguments() {
+        assert_eq!(Ok(Command::Play(default_options())), parse(&[]));
+    }
+
+    #[test]
+    fn parses_every_option() {
+        let command = parse(&arguments(
+            "--mode 4 --size 4 --dimensions 3 --win-length 3 --variant misere \
+             --first o --difficulty easy --seed 42 --games 10 --move-time 5 --search-nodes 900 \
+             --mcts o --iterations 50 --exploration 2 --threads 3 --depth 4 --format json \
+             --strategy x --verbose",
+        ));
+        let options = Options {
+            mode: Some(4),
+            size: 4,
+            dimensions: 3,
+            boards: DEFAULT_BOARDS,
+            win_length: Some(3),
+            variant: Some(Variant::Misere),
+            first: Marker::O,
+            difficulty: Difficulty::Easy,
+            seed: Some(42),
+            games: Some(10),
+            time_control: Some(TimeControl::PerMove(Duration::from_secs(5))),
+            search_budget: Some(Budget::Nodes(900)),
+            mcts_players: vec![Marker::O],
+            mcts: mcts::Settings {
+                iterations: 50,
+                exploration: 2.0,
+            },
+            strategy_players: vec![Marker::X],
+            threads: 3,
+            depth: Some(4),
+            format: Format::Json,
+            verbose: true,
+        };
+        assert_eq!(Ok(Command::Play(options)), command);
+    }
+
+    #[test]
+    fn parses_options_joined_to_their_values() {
+        let command = parse(&arguments("--mode=computer-human --games=2"));
+        let options = Options {
+            mode: Some(3),
+            games: Some(2),
+            ..default_options()
+        };
+        assert_eq!(Ok(Command::Play(options)), command);
+    }
+
+    #[test]
+    fn parses_a_game_clock_with_an_increment() {
+        let control = TimeControl::Game {
+            total: Duration::from_secs(300),
+            increment: Duration::from_secs(2),
+        };
+        assert_eq!(
+            Ok(Command::Play(Options {
+                time_control: Some(control),
+                ..default_options()
+            })),
+            parse(&arguments("--clock 300+2"))
+        );
+        let control = TimeControl::Game {
+            total: Duration::from_secs(60),
+            increment: Duration::from_secs(0),
+        };
+        assert_eq!(
+            Ok(Command::Play(Options {
+                time_control: Some(control),
+                ..default_options()
+            })),
+            parse(&arguments("--clock 60"))
+        );
+    }
+
+    #[test]
+    fn parses_a_search_budget() {
+        assert_eq!(
+            Ok(Command::Play(Options {
+                search_budget: Some(Budget::Time(Duration::from_millis(250))),
+                ..default_options()
+            })),
+            parse(&arguments("--search-time 250"))
+        );
+        assert_eq!(
+            Ok(Command::Play(Options {
+                search_budget: Some(Budget::Nodes(5000)),
+                ..default_options()
+            })),
+            parse(&arguments("--search-nodes 5000"))
+        );
+        assert_eq!(
+            Err("Invalid value for --search-nodes: 0".to_string()),
+            parse(&arguments("--search-nodes 0"))
+        );
+    }
+
+    #[test]
+    fn parses_the_players_that_follow_the_strategy() {
+        let options = Options {
+            strategy_players: vec![Marker::X, Marker::O],
+            ..default_options()
+        };
+        assert_eq!(Ok(Command::Play(options)), parse(&arguments("--strategy both")));
+        assert_eq!(
+            Err("--mcts and --strategy choose the same player".to_string()),
+            parse(&arguments("--mcts o --strategy both"))
+        );
+    }
+
+    #[test]
+    fn parses_the_monte_carlo_settings() {
+        let options = Options {
+            mcts_players: vec![Marker::X, Marker::O],
+            mcts: mcts::Settings {
+                iterations: 200,
+                exploration: 0.5,
+            },
+            ..default_options()
+        };
+        assert_eq!(
+            Ok(Command::Play(options)),
+            parse(&arguments("--mcts both --iterations 200 --exploration 0.5"))
+        );
+        assert_eq!(
+            Err("Invalid value for --exploration: -1".to_string()),
+            parse(&arguments("--mcts o --exploration -1"))
+        );
+    }
+
+    #[test]
+    fn rejects_a_clock_without_time() {
+        assert_eq!(
+            Err("Invalid value for --clock: 0+5".to_string()),
+            parse(&arguments("--clock 0+5"))
+        );
+        assert_eq!(
+            Err("Invalid value for --move-time: soon".to_string()),
+            parse(&arguments("--move-time soon"))
+        );
+    }
+
+    #[test]
+    fn asks_for_help() {
+        assert_eq!(Ok(Command::Help), parse(&arguments("--size 4 --help")));
+    }
+
+    #[test]
+    fn parses_a_position_to_analyze() {
+        let command = parse(&arguments("analyze 5,1 --format json"));
+        let options = Options {
+            format: Format::Json,
+            ..default_options()
+        };
+        assert_eq!(Ok(Command::Analyze("5,1".to_string(), options)), command);
+    }
+
+    #[test]
+    fn parses_a_position_to_count_from() {
+        let options = Options {
+            size: 4,
+            depth: Some(3),
+            ..default_options()
+        };
+        assert_eq!(
+            Ok(Command::Perft("1,6".to_string(), options)),
+            parse(&arguments("perft 1,6 --size 4 --depth 3"))
+        );
+        assert_eq!(
+            Ok(Command::Perft(String::new(), default_options())),
+            parse(&arguments("perft"))
+        );
+    }
+
+    #[test]
+    fn parses_the_solve_command() {
+        assert_eq!(Ok(Command::Solve), parse(&arguments("solve")));
+        assert_eq!(
+            Err("Unexpected argument: --size".to_string()),
+            parse(&arguments("solve --size 4"))
+        );
+    }
+
+    #[test]
+    fn rejects_an_analysis_without_a_position() {
+        assert_eq!(
+            Err("Missing position to analyze".to_string()),
+            parse(&arguments("analyze --size 3"))
+        );
+    }
+
+    #[test]
+    fn rejects_an_unknown_option() {
+        assert_eq!(
+            Err("Unknown option: --colour".to_string()),
+            parse(&arguments("--colour red"))
+        );
+    }
+
+    #[test]
+    fn rejects_an_option_without_a_value() {
+        assert_eq!(
+            Err("Missing value for --games".to_string()),
+            parse(&arguments("--games"))
+        );
+    }
+
+    #[test]
+    fn rejects_an_invalid_value() {
+        assert_eq!(
+            Err("Invalid value for --mode: 5".to_string()),
+            parse(&arguments("--mode 5"))
+        );
+    }
+
+    #[test]
+    fn rejects_a_win_length_longer_than_the_board() {
+        assert_eq!(
+            Err("Invalid value for --win-length: 4".to_string()),
+            parse(&arguments("--win-length 4"))
+        );
+    }
+
+    #[test]
+    fn rejects_a_board_with_more_than_three_dimensions() {
+        assert_eq!(
+            Err("Invalid value for --dimensions: 4".to_string()),
+            parse(&arguments("--dimensions 4"))
+        );
+    }
+
+    #[test]
+    fn rejects_a_game_without_boards() {
+        assert_eq!(
+            Err("Invalid value for --boards: 0".to_string()),
+            parse(&arguments("--variant notakto --boards 0"))
+        );
+    }
+
+    #[test]
+    fn rejects_only_boards_for_standard() {
+        assert!(parse(&arguments("--variant standard --size 4 --dimensions 3")).is_ok());
+        assert!(parse(&arguments("--variant standard --size 5 --win-length 4")).is_ok());
+        assert_eq!(
+            Err("Invalid value for --boards: 2".to_string()),
+            parse(&arguments("--variant standard --boards 2"))
+        );
+    }
+
+    #[test]
+    fn rejects_only_boards_for_misere() {
+        assert!(parse(&arguments("--variant misere --size 4 --win-length 3")).is_ok());
+        assert_eq!(
+            Err("Invalid value for --boards: 4".to_string()),
+            parse(&arguments("--variant misere --boards 4"))
+        );
+    }
+
+    #[test]
+    fn rejects_only_boards_for_wild() {
+        assert!(parse(&arguments("--variant wild --size 4")).is_ok());
+        assert_eq!(
+            Err("Invalid value for --boards: 1".to_string()),
+            parse(&arguments("--variant wild --boards 1"))
+        );
+    }
+
+    #[test]
+    fn rejects_the_board_options_for_ultimate() {
+        assert_eq!(
+            Err("Invalid value for --size: 5".to_string()),
+            parse(&arguments("analyze 41 --variant ultimate --size 5"))
+        );
+        assert_eq!(
+            Err("Invalid value for --dimensions: 3".to_string()),
+            parse(&arguments("--variant ultimate --dimensions 3"))
+        );
+        assert_eq!(
+            Err("Invalid value for --boards: 2".to_string()),
+            parse(&arguments("--variant ultimate --boards 2"))
+        );
+    }
+
+    #[test]
+    fn rejects_the_board_options_for_numerical() {
+        assert_eq!(
+            Err("Invalid value for --size: 4".to_string()),
+            parse(&arguments("--variant numerical --size 4"))
+        );
+        assert_eq!(
+            Err("Invalid value for --win-length: 2".to_string()),
+            parse(&arguments("--variant numerical --win-length 2"))
+        );
+    }
+
+    #[test]
+    fn rejects_every_option_but_boards_for_notakto() {
+        assert_eq!(
+            Ok(Command::Play(Options {
+                boards: 2,
+                variant: Some(Variant::Notakto),
+                ..default_options()
+            })),
+            parse(&arguments("--variant notakto --boards 2"))
+        );
+        assert_eq!(
+            Err("Invalid value for --size: 4".to_string()),
+            parse(&arguments("--variant notakto --size 4"))
+        );
+        assert_eq!(
+            Err("Invalid value for --win-length: 2".to_string()),
+            parse(&arguments("--variant notakto --win-length 2"))
+        );
+        assert_eq!(
+            Err("Invalid value for --dimensions: 3".to_string()),
+            parse(&arguments("--variant notakto --dimensions 3"))
+        );
+    }
+
+    #[test]
+    fn rejects_the_board_options_for_order_and_chaos() {
+        assert_eq!(
+            Err("Invalid value for --size: 6".to_string()),
+            parse(&arguments("--variant order-and-chaos --size 6"))
+        );
+        assert_eq!(
+            Err("Invalid value for --win-length: 3".to_string()),
+            parse(&arguments("--variant order-and-chaos --win-length 3"))
+        );
+        assert_eq!(
+            Err("Invalid value for --dimensions: 3".to_string()),
+            parse(&arguments("--variant order-and-chaos --dimensions 3"))
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use marker::Marker;
+use std::time::Duration;
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const PER_MOVE_SHARE: u32 = 2;
+const MOVES_TO_PLAN_FOR: u32 = 20;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TimeControl {
+    PerMove(Duration),
+    Game { total: Duration, increment: Duration },
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Clock {
+    control: TimeControl,
+    x_remaining: Duration,
+    o_remaining: Duration,
+}
+
+pub fn build_clock(control: TimeControl) -> Clock {
+    let total = match control {
+        TimeControl::PerMove(limit) => limit,
+        TimeControl::Game { total, .. } => total,
+    };
+    Clock {
+        control,
+        x_remaining: total,
+        o_remaining: total,
+    }
+}
+
+impl Clock {
+    pub fn get_time_left(&self, player: &Marker) -> Duration {
+        match *player {
+            Marker::O => self.o_remaining,
+            _ => self.x_remaining,
+        }
+    }
+
+    pub fn has_run_out(&self, player: &Marker, elapsed: Duration) -> bool {
+        elapsed > self.get_time_left(player)
+    }
+
+    pub fn find_budget(&self, player: &Marker) -> Duration {
+        match self.control {
+            TimeControl::PerMove(limit) => limit / PER_MOVE_SHARE,
+            TimeControl::Game { increment, .. } => {
+                self.get_time_left(player) / MOVES_TO_PLAN_FOR + increment / PER_MOVE_SHARE
+            }
+        }
+    }
+
+    pub fn record_move(self, player: &Marker, elapsed: Duration) -> Clock {
+        let increment = match self.control {
+            TimeControl::PerMove(_) => return self,
+            TimeControl::Game { increment, .. } => increment,
+        };
+        let remaining = self.get_time_left(player).saturating_sub(elapsed) + increment;
+        match *player {
+            Marker::O => Clock {
+                o_remaining: remaining,
+                ..self
+            },
+            _ => Clock {
+                x_remaining: remaining,
+                ..self
+            },
+        }
+    }
+
+    pub fn format(&self) -> String {
+        match self.control {
+            TimeControl::PerMove(limit) => format!("{} per move", format_time(limit)),
+            TimeControl::Game { .. } => format!(
+                "X {} | O {}",
+                format_time(self.x_remaining),
+                format_time(self.o_remaining)
+            ),
+        }
+    }
+}
+
+pub fn format_time(time: Duration) -> String {
+    let seconds = time.as_secs();
+    format!(
+        "{}:{:02}",
+        seconds / SECONDS_PER_MINUTE,
+        seconds % SECONDS_PER_MINUTE
+    )
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+
+    #[cfg(test)]
+    fn seconds(count: u64) -> Duration {
+        Duration::from_secs(count)
+    }
+
+    #[test]
+    fn a_per_move_limit_is_the_same_every_move() {
+        let clock = build_clock(TimeControl::PerMove(seconds(10)))
+            .record_move(&Marker::X, seconds(8));
+        assert_eq!(seconds(10), clock.get_time_left(&Marker::X));
+        assert!(!clock.has_run_out(&Marker::X, seconds(10)));
+        assert!(clock.has_run_out(&Marker::O, seconds(11)));
+    }
+
+    #[test]
+    fn a_game_clock_runs_down_and_adds_the_increment() {
+        let control = TimeControl::Game {
+            total: seconds(300),
+            increment: seconds(2),
+        };
+        let clock = build_clock(control).record_move(&Marker::X, seconds(30));
+        assert_eq!(seconds(272), clock.get_time_left(&Marker::X));
+        assert_eq!(seconds(300), clock.get_time_left(&Marker::O));
+        assert!(clock.has_run_out(&Marker::X, seconds(273)));
+    }
+
+    #[test]
+    fn a_player_who_overruns_the_clock_has_no_time_left() {
+        let control = TimeControl::Game {
+            total: seconds(5),
+            increment: seconds(0),
+        };
+        let clock = build_clock(control).record_move(&Marker::O, seconds(6));
+        assert_eq!(seconds(0), clock.get_time_left(&Marker::O));
+    }
+
+    #[test]
+    fn budgets_a_share_of_the_time_left() {
+        assert_eq!(
+            seconds(5),
+            build_clock(TimeControl::PerMove(seconds(10))).find_budget(&Marker::X)
+        );
+        let control = TimeControl::Game {
+            total: seconds(200),
+            increment: seconds(4),
+        };
+        assert_eq!(seconds(12), build_clock(control).find_budget(&Marker::O));
+    }
+
+    #[test]
+    fn shows_the_time_left_for_each_player() {
+        let control = TimeControl::Game {
+            total: seconds(300),
+            increment: seconds(0),
+        };
+        let clock = build_clock(control).record_move(&Marker::X, seconds(75));
+        assert_eq!("X 3:45 | O 5:00", clock.format());
+        assert_eq!(
+            "0:10 per move",
+            build_clock(TimeControl::PerMove(seconds(10))).format()
+        );
+    }
+}
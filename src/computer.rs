@@ -1,107 +1,583 @@
-use game;
+use marker;
+use outcome;
+use rules::Rules;
 use std::collections::HashMap;
-use board::Board;
+use std::ops::Neg;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use board::{Board, Move};
+use difficulty::Difficulty;
+use random::Random;
+use outcome::Outcome;
+use solved;
 
 const INITIAL_DEPTH: i32 = 0;
 const TIED: i32 = 0;
-const MAX_SCORE: i32 = 1000;
+const MAX_SCORE: i32 = 1_000_000;
+const HEURISTIC_LIMIT: i32 = 100;
 const INCREMENT: i32 = 1;
-const EARLY_STAGES_OF_GAME: usize = 1;
-const FIRST_MOVE: i32 = 4;
-const SECOND_MOVE: i32 = 0;
+const UNBOUNDED: i32 = MAX_SCORE * 2;
+const MEDIUM_MISTAKE_CHANCE: u64 = 50;
+const UNLIMITED_DEPTH: i32 = i32::MAX;
+const SOLVABLE_SPACES: i32 = 9;
+const DEFAULT_BUDGET: Budget = Budget::Nodes(20_000);
+pub const SINGLE_THREAD: usize = 1;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Budget {
+    Time(Duration),
+    Nodes(u64),
+}
 
-pub fn find_space(board: &Board) -> i32 {
-    if is_game_in_early_stages(board) {
-        choose_strategic_space(board)
-    } else {
-        find_best_score(board, INITIAL_DEPTH, HashMap::new())
+struct SearchLimit {
+    depth: i32,
+    deadline: Option<Instant>,
+    max_nodes: Option<u64>,
+    nodes: AtomicU64,
+    threads: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Score {
+    pub value: i32,
+    pub exact: bool,
+}
+
+impl Neg for Score {
+    type Output = Score;
+
+    fn neg(self) -> Score {
+        Score {
+            value: -self.value,
+            ..self
+        }
     }
 }
 
-fn find_best_score(board: &Board, depth: i32, mut best_score: HashMap<i32, i32>) -> i32 {
-    if game::is_game_over(board) {
-        score_scenarios(board, depth)
-    } else {
-        for space in &board.get_available_spaces() {
-            let emulated_board = board.clone().place_marker(*space);
-            best_score.insert(
-                *space,
-                -find_best_score(&emulated_board, depth + INCREMENT, HashMap::new()),
-            );
+#[derive(PartialEq, Debug)]
+pub enum Evaluation {
+    Win(i32),
+    Draw,
+    Loss(i32),
+    Unknown,
+}
+
+pub fn choose_move<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    difficulty: &Difficulty,
+    random: &mut Random,
+    budget: Option<Budget>,
+    threads: usize,
+) -> (Move, HashMap<Move, Score>) {
+    match *difficulty {
+        Difficulty::Easy => (choose_random_move(rules, board, random), HashMap::new()),
+        Difficulty::Medium if random.chance(MEDIUM_MISTAKE_CHANCE) => {
+            (choose_random_move(rules, board, random), HashMap::new())
+        }
+        _ => find_scored_move(rules, board, budget, threads),
+    }
+}
+
+fn choose_random_move<R: Rules + ?Sized>(rules: &R, board: &Board, random: &mut Random) -> Move {
+    let available_moves = rules.find_legal_moves(board);
+    available_moves[random.below(available_moves.len())]
+}
+
+fn find_scored_move<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    budget: Option<Budget>,
+    threads: usize,
+) -> (Move, HashMap<Move, Score>) {
+    match rules.find_solution(board) {
+        Some(solution) => (solution.best_move, score_solved_moves(rules, board)),
+        None => {
+            let scores = score_candidates(rules, board, budget, threads);
+            (find_highest_score(&scores).0, scores)
         }
-        analyse_board(&best_score, depth)
     }
 }
 
-fn score_scenarios(board: &Board, depth: i32) -> i32 {
-    if game::is_game_tied(board) {
+fn score_solved_moves<R: Rules + ?Sized>(rules: &R, board: &Board) -> HashMap<Move, Score> {
+    let depth = INITIAL_DEPTH + INCREMENT;
+    rules
+        .find_legal_moves(board)
+        .into_iter()
+        .map(|next_move| {
+            let next_board = board.clone().play(rules, next_move);
+            let value = match rules.find_solution(&next_board) {
+                Some(solution) => score_solution(solution.value, depth),
+                None => score_outcome(rules, &next_board, &rules.find_outcome(&next_board), depth),
+            };
+            (next_move, -Score { value, exact: true })
+        })
+        .collect()
+}
+
+fn score_solution(plies: i32, depth: i32) -> i32 {
+    if plies == solved::DRAWN {
         TIED
     } else {
-        -MAX_SCORE / depth
+        plies.signum() * MAX_SCORE / (depth + plies.abs())
     }
 }
 
-fn analyse_board(best_score: &HashMap<i32, i32>, depth: i32) -> i32 {
-    let space_with_highest_score: (i32, i32) = find_highest_score(best_score);
-    if current_turn(depth) {
-        choose_best_space(space_with_highest_score)
+fn deepen_search<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    budget: Budget,
+    threads: usize,
+) -> HashMap<Move, Score> {
+    let open_spaces = board.get_number_of_spaces() - board.get_spaces().len() as i32;
+    let deepest = find_search_depth(rules, board).min(open_spaces);
+    let (deadline, max_nodes) = match budget {
+        Budget::Time(time) => (Some(Instant::now() + time), None),
+        Budget::Nodes(nodes) => (None, Some(nodes)),
+    };
+    let mut limit = SearchLimit {
+        depth: INITIAL_DEPTH,
+        deadline: None,
+        max_nodes: None,
+        nodes: AtomicU64::new(0),
+        threads,
+    };
+    let mut best_scores = score_available_moves(rules, board, INITIAL_DEPTH, &limit);
+    limit.deadline = deadline;
+    limit.max_nodes = max_nodes;
+    while limit.depth < deepest && !has_expired(&limit) {
+        limit.depth += INCREMENT;
+        let scores = score_available_moves(rules, board, INITIAL_DEPTH, &limit);
+        if has_expired(&limit) {
+            break;
+        }
+        best_scores = scores;
+    }
+    best_scores
+}
+
+pub fn score_moves<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    threads: usize,
+) -> HashMap<Move, Score> {
+    if rules.is_game_over(board) {
+        HashMap::new()
     } else {
-        choose_highest_score(space_with_highest_score)
+        let limit = search_fully(rules, board, threads);
+        score_available_moves(rules, board, INITIAL_DEPTH, &limit)
     }
 }
 
-fn find_highest_score(best_score: &HashMap<i32, i32>) -> (i32, i32) {
-    let mut scores_to_compare: Vec<(&i32, &i32)> = best_score.iter().collect();
-    scores_to_compare.sort_by(|key, value| value.1.cmp(key.1));
-    (*scores_to_compare[0].0, *scores_to_compare[0].1)
+pub fn score_candidates<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    budget: Option<Budget>,
+    threads: usize,
+) -> HashMap<Move, Score> {
+    match budget {
+        _ if rules.is_game_over(board) => HashMap::new(),
+        Some(budget) => deepen_search(rules, board, budget, threads),
+        None if is_too_large_to_solve(rules, board) => {
+            deepen_search(rules, board, DEFAULT_BUDGET, threads)
+        }
+        None => score_moves(rules, board, threads),
+    }
 }
 
-fn choose_best_space(best_space_with_score: (i32, i32)) -> i32 {
-    best_space_with_score.0
+pub fn accepts_draw<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    budget: Option<Budget>,
+    threads: usize,
+) -> bool {
+    if let Some(solution) = rules.find_solution(board) {
+        return solution.value == solved::DRAWN;
+    }
+    let scores = score_candidates(rules, board, budget, threads);
+    scores.is_empty() || evaluate(&find_highest_score(&scores).1) == Evaluation::Draw
+}
+
+pub fn evaluate(score: &Score) -> Evaluation {
+    let value = score.value;
+    if value.abs() <= HEURISTIC_LIMIT {
+        if score.exact {
+            Evaluation::Draw
+        } else {
+            Evaluation::Unknown
+        }
+    } else {
+        let plies = (MAX_SCORE + value.abs() / 2) / value.abs();
+        if value > TIED {
+            Evaluation::Win((plies + INCREMENT) / 2)
+        } else {
+            Evaluation::Loss(plies / 2)
+        }
+    }
 }
 
-fn choose_highest_score(best_space_with_score: (i32, i32)) -> i32 {
-    best_space_with_score.1
+fn find_best_score<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    depth: i32,
+    limit: &SearchLimit,
+    alpha: i32,
+    beta: i32,
+) -> Score {
+    limit.nodes.fetch_add(INCREMENT as u64, Ordering::Relaxed);
+    let outcome = rules.find_outcome(board);
+    if outcome::is_over(&outcome) {
+        return Score {
+            value: score_outcome(rules, board, &outcome, depth),
+            exact: true,
+        };
+    }
+    if has_expired(limit) {
+        return Score {
+            value: TIED,
+            exact: false,
+        };
+    }
+    if depth > limit.depth {
+        return Score {
+            value: rules
+                .find_heuristic_score(board)
+                .clamp(-HEURISTIC_LIMIT, HEURISTIC_LIMIT),
+            exact: false,
+        };
+    }
+    let mut alpha = alpha;
+    let mut best_score = Score {
+        value: -UNBOUNDED,
+        exact: true,
+    };
+    let mut emulated_board = board.clone();
+    for next_move in rules.find_legal_moves(board) {
+        emulated_board = emulated_board.play(rules, next_move);
+        let score =
+            -find_best_score(rules, &emulated_board, depth + INCREMENT, limit, -beta, -alpha);
+        emulated_board = emulated_board.undo();
+        best_score = Score {
+            value: best_score.value.max(score.value),
+            exact: best_score.exact && score.exact,
+        };
+        alpha = alpha.max(score.value);
+        if alpha >= beta {
+            break;
+        }
+    }
+    best_score
 }
 
-fn current_turn(depth: i32) -> bool {
-    depth == 0
+fn score_available_moves<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    depth: i32,
+    limit: &SearchLimit,
+) -> HashMap<Move, Score> {
+    let moves = rules.find_legal_moves(board);
+    if limit.threads <= SINGLE_THREAD {
+        return score_each_move(rules, board, depth, limit, &moves);
+    }
+    let shares: Vec<Vec<Move>> = (0..limit.threads)
+        .map(|thread| {
+            moves
+                .iter()
+                .skip(thread)
+                .step_by(limit.threads)
+                .cloned()
+                .collect()
+        })
+        .collect();
+    thread::scope(|scope| {
+        let workers: Vec<_> = shares
+            .iter()
+            .map(|share| scope.spawn(move || score_each_move(rules, board, depth, limit, share)))
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("A search thread panicked"))
+            .collect()
+    })
 }
 
-fn is_game_in_early_stages(board: &Board) -> bool {
-    board.get_spaces().len() <= EARLY_STAGES_OF_GAME
+fn score_each_move<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    depth: i32,
+    limit: &SearchLimit,
+    moves: &[Move],
+) -> HashMap<Move, Score> {
+    let mut best_score: HashMap<Move, Score> = HashMap::new();
+    for next_move in moves {
+        let emulated_board = board.clone().play(rules, *next_move);
+        best_score.insert(
+            *next_move,
+            -find_best_score(
+                rules,
+                &emulated_board,
+                depth + INCREMENT,
+                limit,
+                -UNBOUNDED,
+                UNBOUNDED,
+            ),
+        );
+    }
+    best_score
 }
 
-fn choose_strategic_space(board: &Board) -> i32 {
-    if board.is_space_available(&FIRST_MOVE) {
-        FIRST_MOVE
+fn score_outcome<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    outcome: &Outcome,
+    depth: i32,
+) -> i32 {
+    if *outcome == Outcome::Draw {
+        TIED
+    } else if outcome::is_won_by(outcome, &rules.find_current_player(board)) {
+        MAX_SCORE / depth
     } else {
-        SECOND_MOVE
+        -MAX_SCORE / depth
     }
 }
 
+pub fn find_highest_score(best_score: &HashMap<Move, Score>) -> (Move, Score) {
+    let mut scores_to_compare: Vec<(&Move, &Score)> = best_score.iter().collect();
+    scores_to_compare.sort_by(|first, second| {
+        second
+            .1
+            .value
+            .cmp(&first.1.value)
+            .then(first.0.space.cmp(&second.0.space))
+            .then(marker::inspect(&second.0.marker).cmp(&marker::inspect(&first.0.marker)))
+    });
+    (*scores_to_compare[0].0, *scores_to_compare[0].1)
+}
+
+fn find_search_depth<R: Rules + ?Sized>(rules: &R, board: &Board) -> i32 {
+    rules.find_search_depth(board).unwrap_or(UNLIMITED_DEPTH)
+}
+
+fn search_fully<R: Rules + ?Sized>(rules: &R, board: &Board, threads: usize) -> SearchLimit {
+    SearchLimit {
+        depth: find_search_depth(rules, board),
+        deadline: None,
+        max_nodes: None,
+        nodes: AtomicU64::new(0),
+        threads,
+    }
+}
+
+fn has_expired(limit: &SearchLimit) -> bool {
+    limit
+        .deadline
+        .is_some_and(|deadline| Instant::now() >= deadline)
+        || limit
+            .max_nodes
+            .is_some_and(|max_nodes| limit.nodes.load(Ordering::Relaxed) >= max_nodes)
+}
+
+fn is_too_large_to_solve<R: Rules + ?Sized>(rules: &R, board: &Board) -> bool {
+    rules.find_search_depth(board).is_some()
+        || board.get_number_of_spaces() > SOLVABLE_SPACES
+        || rules.find_legal_moves(board).len() > SOLVABLE_SPACES as usize
+}
+
 pub mod tests {
     #[cfg(test)]
     use super::*;
     #[cfg(test)]
     use board::tests::set_up_board;
+    #[cfg(test)]
+    use ultimate::tests::set_up_ultimate_board;
+    #[cfg(test)]
+    use notakto::tests::set_up_notakto_board;
+    #[cfg(test)]
+    use order_and_chaos::tests::set_up_order_and_chaos_board;
+    #[cfg(test)]
+    use random::build_random;
+    #[cfg(test)]
+    use marker::Marker;
+    #[cfg(test)]
+    use rules::{find_rules, StandardRules};
+    #[cfg(test)]
+    use ultimate::UltimateRules;
+    #[cfg(test)]
+    use variant::Variant;
+
+    #[cfg(test)]
+    fn exact(value: i32) -> Score {
+        Score { value, exact: true }
+    }
+
+    #[cfg(test)]
+    pub fn find_move<R: Rules + ?Sized>(rules: &R, board: &Board, threads: usize) -> Move {
+        find_scored_move(rules, board, None, threads).0
+    }
+
+    #[cfg(test)]
+    fn find_move_within<R: Rules + ?Sized>(
+        rules: &R,
+        board: &Board,
+        budget: Budget,
+        threads: usize,
+    ) -> Move {
+        find_scored_move(rules, board, Some(budget), threads).0
+    }
+
+    #[cfg(test)]
+    fn find_space(board: &Board) -> i32 {
+        find_move(find_rules(board), board, SINGLE_THREAD).space
+    }
+
+    #[cfg(test)]
+    fn place(space: i32, marker: Marker) -> Move {
+        Move { space, marker }
+    }
+
+    #[cfg(test)]
+    struct CornerRules;
+
+    #[cfg(test)]
+    impl Rules for CornerRules {
+        fn find_winner(&self, board: &Board) -> Marker {
+            board
+                .get_spaces()
+                .iter()
+                .position(|space| *space == 8)
+                .map_or(Marker::NA, |turn| board.get_markers()[turn])
+        }
+
+        fn is_game_over(&self, board: &Board) -> bool {
+            !board.is_space_available(&8) || self.find_playable_spaces(board).is_empty()
+        }
+
+        fn is_game_tied(&self, board: &Board) -> bool {
+            board.is_space_available(&8)
+        }
+    }
+
+    #[cfg(test)]
+    struct HorizonRules;
+
+    #[cfg(test)]
+    impl Rules for HorizonRules {
+        fn find_heuristic_score(&self, board: &Board) -> i32 {
+            if board.get_spaces().last() == Some(&7) {
+                -HEURISTIC_LIMIT
+            } else {
+                TIED
+            }
+        }
+
+        fn find_search_depth(&self, _board: &Board) -> Option<i32> {
+            Some(INITIAL_DEPTH)
+        }
+    }
 
     #[test]
-    fn checks_if_it_is_the_first_two_moves_of_the_game_no_moves() {
-        let board: Board = set_up_board(3, vec![]);
-        assert!(is_game_in_early_stages(&board));
+    fn an_easy_computer_chooses_an_available_space() {
+        let board: Board = set_up_board(3, vec![0, 1, 2, 3, 4, 8, 5]);
+        let mut random = build_random(1);
+        for _ in 0..10 {
+            let (next_move, scores) = choose_move(
+                find_rules(&board),
+                &board,
+                &Difficulty::Easy,
+                &mut random,
+                None,
+                SINGLE_THREAD,
+            );
+            let space = next_move.space;
+            assert!(space == 6 || space == 7);
+            assert!(scores.is_empty());
+        }
     }
 
     #[test]
-    fn checks_if_it_is_the_first_two_moves_of_the_game_one_move() {
-        let board: Board = set_up_board(3, vec![0]);
-        assert!(is_game_in_early_stages(&board));
+    fn a_hard_computer_always_chooses_the_best_space() {
+        let board: Board = set_up_board(3, vec![0, 4, 1, 6]);
+        let mut random = build_random(1);
+        for _ in 0..10 {
+            assert_eq!(
+                place(2, Marker::X),
+                choose_move(
+                    find_rules(&board),
+                    &board,
+                    &Difficulty::Hard,
+                    &mut random,
+                    None,
+                    SINGLE_THREAD
+                )
+                .0
+            );
+        }
     }
 
     #[test]
-    fn checks_if_it_is_not_in_the_first_two_moves_of_the_game() {
-        let board: Board = set_up_board(3, vec![0, 4]);
-        assert!(!is_game_in_early_stages(&board));
+    fn scores_every_move_of_a_solved_position_as_the_search_does() {
+        let board: Board = set_up_board(3, vec![0, 4, 8, 1]);
+        let rules = find_rules(&board);
+        assert_eq!(
+            score_moves(rules, &board, SINGLE_THREAD),
+            score_solved_moves(rules, &board)
+        );
+        let board: Board = set_up_board(3, vec![0, 4, 1, 6]);
+        let rules = find_rules(&board);
+        let mut random = build_random(1);
+        let (next_move, scores) =
+            choose_move(rules, &board, &Difficulty::Hard, &mut random, None, SINGLE_THREAD);
+        assert_eq!(place(2, Marker::X), next_move);
+        assert_eq!(Evaluation::Win(1), evaluate(&scores[&next_move]));
+        assert_eq!(Evaluation::Loss(1), evaluate(&scores[&place(3, Marker::X)]));
+    }
+
+    #[test]
+    fn scores_every_available_space() {
+        let board: Board = set_up_board(3, vec![0, 4, 1, 6]);
+        let scores = score_moves(find_rules(&board), &board, SINGLE_THREAD);
+        assert_eq!(5, scores.len());
+        assert_eq!(Evaluation::Win(1), evaluate(&scores[&place(2, Marker::X)]));
+        assert_eq!(Evaluation::Loss(1), evaluate(&scores[&place(8, Marker::X)]));
+    }
+
+    #[test]
+    fn scores_a_drawn_space() {
+        let board: Board = set_up_board(3, vec![0, 4, 8, 2, 6, 7, 1, 3]);
+        assert_eq!(
+            Evaluation::Draw,
+            evaluate(&score_moves(find_rules(&board), &board, SINGLE_THREAD)[&place(5, Marker::X)])
+        );
+    }
+
+    #[test]
+    fn accepts_a_draw_only_in_a_drawn_position() {
+        let board: Board = set_up_board(3, vec![4, 0]);
+        assert!(accepts_draw(find_rules(&board), &board, None, SINGLE_THREAD));
+        let board: Board = set_up_board(3, vec![0, 4, 1, 6]);
+        assert!(!accepts_draw(find_rules(&board), &board, None, SINGLE_THREAD));
+        let board: Board = set_up_board(3, vec![0, 1, 4]);
+        assert!(!accepts_draw(find_rules(&board), &board, None, SINGLE_THREAD));
+    }
+
+    #[test]
+    fn declines_a_draw_that_the_search_could_not_prove() {
+        let board: Board = set_up_ultimate_board(vec![40]);
+        let rules = find_rules(&board);
+        assert!(!accepts_draw(rules, &board, Some(Budget::Nodes(1_000)), SINGLE_THREAD));
+        let board: Board = set_up_board(4, vec![5, 6]);
+        let rules = find_rules(&board);
+        assert!(!accepts_draw(rules, &board, Some(Budget::Nodes(1_000)), SINGLE_THREAD));
+    }
+
+    #[test]
+    fn evaluates_wins_and_losses_by_the_number_of_moves() {
+        assert_eq!(Evaluation::Win(1), evaluate(&exact(MAX_SCORE)));
+        assert_eq!(Evaluation::Win(2), evaluate(&exact(MAX_SCORE / 3)));
+        assert_eq!(Evaluation::Loss(1), evaluate(&exact(-MAX_SCORE / 2)));
+        assert_eq!(Evaluation::Loss(3), evaluate(&exact(-MAX_SCORE / 6)));
+        assert_eq!(Evaluation::Win(5), evaluate(&exact(MAX_SCORE / 9)));
     }
 
     #[test]
@@ -184,6 +660,21 @@ pub mod tests {
         assert_ne!(5, find_space(&board));
     }
 
+    #[test]
+    fn makes_the_same_forced_move_in_every_orientation() {
+        for board in &[
+            set_up_board(3, vec![0, 1, 4]),
+            set_up_board(3, vec![0, 4, 1, 6]),
+            set_up_board(3, vec![0, 8, 6]),
+        ] {
+            let next_move = find_move(find_rules(board), board, SINGLE_THREAD);
+            for (symmetry, transform) in board.find_transforms() {
+                let transformed_move = find_move(find_rules(&transform), &transform, SINGLE_THREAD);
+                assert_eq!(next_move, board.restore_move(symmetry, transformed_move));
+            }
+        }
+    }
+
     #[test]
     fn chooses_the_only_available_space() {
         let board: Board = set_up_board(3, vec![0, 1, 2, 3, 4, 8, 5, 6]);
@@ -220,4 +711,238 @@ pub mod tests {
         assert_eq!(3, find_space(&board));
     }
 
+    #[test]
+    fn avoids_completing_a_line_in_misere() {
+        let board: Board = set_up_board(3, vec![0, 3, 1, 4]).with_variant(Variant::Misere);
+        assert_ne!(2, find_space(&board));
+    }
+
+    #[test]
+    fn scores_a_space_that_completes_a_line_as_a_loss_in_misere() {
+        let board: Board = set_up_board(3, vec![0, 3, 1, 4]).with_variant(Variant::Misere);
+        assert_eq!(
+            Evaluation::Loss(0),
+            evaluate(&score_moves(find_rules(&board), &board, SINGLE_THREAD)[&place(2, Marker::X)])
+        );
+    }
+
+    #[test]
+    fn takes_the_middle_first_in_misere() {
+        let board: Board = set_up_board(3, vec![]).with_variant(Variant::Misere);
+        assert_eq!(4, find_space(&board));
+    }
+
+    #[test]
+    fn searches_for_the_second_move_in_misere() {
+        let board: Board = set_up_board(3, vec![4]).with_variant(Variant::Misere);
+        assert_eq!(None, find_rules(&board).find_solution(&board));
+    }
+
+    #[test]
+    fn completes_a_line_of_either_marker_in_wild() {
+        let board: Board = set_up_board(3, vec![0, 4, 1]).with_variant(Variant::Wild);
+        assert_eq!(place(2, Marker::X), find_move(find_rules(&board), &board, SINGLE_THREAD));
+    }
+
+    #[test]
+    fn scores_both_markers_for_each_space_in_wild() {
+        let board: Board = set_up_board(3, vec![0, 4, 1, 8, 2]).with_variant(Variant::Wild);
+        assert_eq!(HashMap::new(), score_moves(find_rules(&board), &board, SINGLE_THREAD));
+        let board: Board = set_up_board(3, vec![0, 1, 2, 4, 3, 5, 7]).with_variant(Variant::Wild);
+        assert_eq!(4, score_moves(find_rules(&board), &board, SINGLE_THREAD).len());
+    }
+
+    #[test]
+    fn searches_wild_within_the_default_budget_while_it_has_many_moves() {
+        let board: Board = set_up_board(3, vec![]);
+        assert!(!is_too_large_to_solve(find_rules(&board), &board));
+        let board = board.with_variant(Variant::Wild);
+        assert!(is_too_large_to_solve(find_rules(&board), &board));
+        let next_move = find_move(find_rules(&board), &board, SINGLE_THREAD);
+        assert!(find_rules(&board).is_move_legal(&board, &next_move));
+        let board: Board = set_up_board(3, vec![0, 4, 1, 8, 5]).with_variant(Variant::Wild);
+        assert!(!is_too_large_to_solve(find_rules(&board), &board));
+    }
+
+    #[test]
+    fn completes_three_sub_boards_in_a_row_in_ultimate() {
+        let board: Board = set_up_ultimate_board(vec![
+            0, 40, 9, 50, 18, 58, 3, 67, 12, 78, 21, 60, 6, 57, 15, 32,
+        ]);
+        assert_eq!(24, find_space(&board));
+    }
+
+    #[test]
+    fn wins_the_sub_board_it_is_sent_to_rather_than_play_the_first_open_space() {
+        let board: Board = set_up_ultimate_board(vec![0, 60, 10, 27]);
+        assert_eq!(20, find_space(&board));
+    }
+
+    #[test]
+    fn limits_the_search_depth_in_ultimate() {
+        let board: Board = set_up_ultimate_board(vec![40]);
+        assert_eq!(2, find_search_depth(&UltimateRules, &board));
+        assert_eq!(8, score_moves(find_rules(&board), &board, SINGLE_THREAD).len());
+    }
+
+    #[test]
+    fn completes_a_line_through_the_middle_of_a_cube() {
+        let board: Board = set_up_board(4, vec![])
+            .with_dimensions(3)
+            .place_marker(&StandardRules, 0)
+            .place_marker(&StandardRules, 1)
+            .place_marker(&StandardRules, 21)
+            .place_marker(&StandardRules, 2)
+            .place_marker(&StandardRules, 42);
+        assert_eq!(63, find_space(&board));
+    }
+
+    #[test]
+    fn blocks_an_open_three_in_a_row_in_a_cube() {
+        let board: Board = set_up_board(4, vec![])
+            .with_dimensions(3)
+            .place_marker(&StandardRules, 20)
+            .place_marker(&StandardRules, 0)
+            .place_marker(&StandardRules, 21)
+            .place_marker(&StandardRules, 63)
+            .place_marker(&StandardRules, 22);
+        assert_eq!(23, find_space(&board));
+    }
+
+    #[test]
+    fn avoids_killing_the_last_board_in_notakto() {
+        let board: Board = set_up_notakto_board(2, vec![0, 1, 2, 9, 13]);
+        assert_eq!(Marker::X, find_move(find_rules(&board), &board, SINGLE_THREAD).marker);
+        assert_ne!(17, find_space(&board));
+    }
+
+    #[test]
+    fn avoids_killing_a_board_when_it_hands_over_the_last_board() {
+        let board: Board = set_up_notakto_board(2, vec![0, 1]);
+        assert_eq!(3, find_space(&board));
+    }
+
+    #[test]
+    fn chaos_breaks_an_open_four() {
+        let board: Board = set_up_order_and_chaos_board(vec![
+            (6, Marker::X),
+            (30, Marker::O),
+            (7, Marker::X),
+            (8, Marker::X),
+            (10, Marker::X),
+        ]);
+        assert_eq!(place(9, Marker::O), find_move(find_rules(&board), &board, SINGLE_THREAD));
+    }
+
+    #[test]
+    fn searches_a_game_with_its_own_rules() {
+        let board: Board = set_up_board(3, vec![0, 4]);
+        assert_eq!(place(8, Marker::X), find_move(&CornerRules, &board, SINGLE_THREAD));
+        assert_eq!(
+            Evaluation::Win(1),
+            evaluate(&score_moves(&CornerRules, &board, SINGLE_THREAD)[&place(8, Marker::X)])
+        );
+    }
+
+    #[test]
+    fn finds_the_best_move_within_a_time_budget() {
+        let board: Board = set_up_board(3, vec![0, 4, 1, 6]);
+        let budget = Budget::Time(Duration::from_millis(200));
+        let rules = find_rules(&board);
+        assert_eq!(place(2, Marker::X), find_move_within(rules, &board, budget, SINGLE_THREAD));
+        let board: Board = set_up_board(3, vec![0, 4, 8]);
+        assert_eq!(1, find_move_within(rules, &board, budget, SINGLE_THREAD).space);
+    }
+
+    #[test]
+    fn stops_searching_when_the_time_runs_out() {
+        let board: Board = set_up_board(5, vec![12]);
+        let started = Instant::now();
+        let budget = Budget::Time(Duration::from_millis(50));
+        let next_move = find_move_within(find_rules(&board), &board, budget, SINGLE_THREAD);
+        assert!(board.is_space_available(&next_move.space));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn a_node_budget_gives_the_same_move_every_time() {
+        let board: Board = set_up_board(4, vec![5, 0]);
+        let budget = Budget::Nodes(2000);
+        let next_move = find_move_within(find_rules(&board), &board, budget, SINGLE_THREAD);
+        assert_eq!(next_move, find_move_within(find_rules(&board), &board, budget, SINGLE_THREAD));
+    }
+
+    #[test]
+    fn a_node_budget_gives_the_same_move_with_any_number_of_threads() {
+        let board: Board = set_up_board(4, vec![5, 0]);
+        let rules = find_rules(&board);
+        let budget = Budget::Nodes(2000);
+        let next_move = find_move_within(rules, &board, budget, SINGLE_THREAD);
+        for threads in 2..5 {
+            assert_eq!(next_move, find_move_within(rules, &board, budget, threads));
+        }
+    }
+
+    #[test]
+    fn scores_every_move_the_same_with_any_number_of_threads() {
+        let board: Board = set_up_board(3, vec![4]);
+        let rules = find_rules(&board);
+        let scores = score_moves(rules, &board, SINGLE_THREAD);
+        assert_eq!(scores, score_moves(rules, &board, 3));
+        assert_eq!(scores, score_moves(rules, &board, 16));
+    }
+
+    #[test]
+    fn blocks_a_threat_on_a_larger_board() {
+        let board: Board = set_up_board(4, vec![0, 5, 1, 6, 2]);
+        assert_eq!(3, find_space(&board));
+    }
+
+    #[test]
+    fn prefers_the_centre_when_the_search_is_cut_off() {
+        let board: Board = set_up_board(5, vec![0, 24]);
+        let budget = Budget::Nodes(100);
+        assert_eq!(12, find_move_within(find_rules(&board), &board, budget, SINGLE_THREAD).space);
+    }
+
+    #[test]
+    fn scores_every_candidate_within_a_budget() {
+        let board: Board = set_up_board(4, vec![5, 0]);
+        let rules = find_rules(&board);
+        let scores = score_candidates(rules, &board, Some(Budget::Nodes(2000)), SINGLE_THREAD);
+        assert_eq!(14, scores.len());
+        assert_eq!(
+            find_move_within(rules, &board, Budget::Nodes(2000), SINGLE_THREAD),
+            find_highest_score(&scores).0
+        );
+        assert!(score_candidates(rules, &set_up_board(3, vec![0, 3, 1, 4, 2]), None, 1).is_empty());
+    }
+
+    #[test]
+    fn a_heuristic_score_is_not_mistaken_for_a_win() {
+        let heuristic = Score {
+            value: HEURISTIC_LIMIT,
+            exact: false,
+        };
+        assert_eq!(Evaluation::Unknown, evaluate(&heuristic));
+        assert_eq!(Evaluation::Unknown, evaluate(&-heuristic));
+        assert_eq!(Evaluation::Draw, evaluate(&exact(TIED)));
+    }
+
+    #[test]
+    fn scores_the_horizon_of_a_depth_limited_search_by_the_heuristic() {
+        let board: Board = set_up_board(3, vec![0, 4]);
+        let scores = score_moves(&HorizonRules, &board, SINGLE_THREAD);
+        assert_eq!(place(7, Marker::X), find_highest_score(&scores).0);
+        assert_eq!(HEURISTIC_LIMIT, scores[&place(7, Marker::X)].value);
+    }
+
+    #[test]
+    fn a_search_cut_off_by_its_depth_does_not_call_a_move_a_draw() {
+        let board: Board = set_up_ultimate_board(vec![40]);
+        let scores = score_moves(find_rules(&board), &board, SINGLE_THREAD);
+        assert!(scores
+            .values()
+            .all(|score| evaluate(score) == Evaluation::Unknown));
+    }
 }
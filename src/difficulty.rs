@@ -0,0 +1,32 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+pub fn parse(name: &str) -> Option<Difficulty> {
+    match name {
+        "easy" => Some(Difficulty::Easy),
+        "medium" => Some(Difficulty::Medium),
+        "hard" => Some(Difficulty::Hard),
+        _ => None,
+    }
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+
+    #[test]
+    fn parses_each_difficulty() {
+        assert_eq!(Some(Difficulty::Easy), parse("easy"));
+        assert_eq!(Some(Difficulty::Medium), parse("medium"));
+        assert_eq!(Some(Difficulty::Hard), parse("hard"));
+    }
+
+    #[test]
+    fn does_not_parse_an_unknown_difficulty() {
+        assert_eq!(None, parse("impossible"));
+    }
+}
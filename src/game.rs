@@ -1,54 +1,52 @@
-use lines;
-use marker;
+use rules;
 use marker::Marker;
 use board::Board;
 
-pub fn find_current_player(board: &Board) -> Marker {
-    if board.get_spaces().len() % 2 == 0 {
-        Marker::X
-    } else {
-        Marker::O
-    }
-}
-
 pub fn is_game_over(board: &Board) -> bool {
-    is_game_tied(board) || is_game_won(board)
-}
-
-pub fn is_game_tied(board: &Board) -> bool {
-    !is_game_won(board) && board.get_available_spaces().is_empty()
-}
-
-fn is_game_won(board: &Board) -> bool {
-    is_game_won_by(board, &Marker::X) || is_game_won_by(board, &Marker::O)
-}
-
-pub fn is_game_won_by(board: &Board, player: &Marker) -> bool {
-    let winning_scenarios = lines::find_all_lines(board);
-    winning_scenarios
-        .iter()
-        .any(|line| is_line_won_by(line, player))
-}
-
-fn is_line_won_by(line: &[String], player: &Marker) -> bool {
-    line.iter().all(|space| space == &marker::inspect(player))
+    rules::find_rules(board).is_game_over(board)
 }
 
 pub fn find_winner(board: &Board) -> Marker {
-    if is_game_won_by(board, &Marker::X) {
-        Marker::X
-    } else if is_game_won_by(board, &Marker::O) {
-        Marker::O
-    } else {
-        Marker::NA
-    }
+    rules::find_rules(board).find_winner(board)
 }
 
 pub mod tests {
     #[cfg(test)]
     use super::*;
     #[cfg(test)]
+    use board::{build_board, Move};
+    #[cfg(test)]
     use board::tests::set_up_board;
+    #[cfg(test)]
+    use ultimate::tests::set_up_ultimate_board;
+    #[cfg(test)]
+    use rules::{find_target_sum, NumericalRules, Rules, StandardRules, WildRules};
+    #[cfg(test)]
+    use variant::Variant;
+
+    #[cfg(test)]
+    fn find_current_player(board: &Board) -> Marker {
+        rules::find_rules(board).find_current_player(board)
+    }
+
+    #[cfg(test)]
+    fn is_game_won(board: &Board) -> bool {
+        rules::find_rules(board).find_line_completer(board) != Marker::NA
+    }
+
+    #[cfg(test)]
+    use outcome::Outcome;
+
+    #[cfg(test)]
+    fn find_outcome(board: &Board) -> Outcome {
+        rules::find_rules(board).find_outcome(board)
+    }
+
+    #[cfg(test)]
+    fn is_game_tied(board: &Board) -> bool {
+        rules::find_rules(board).is_game_tied(board)
+    }
+
     #[test]
     fn x_is_current_player_at_start_of_game() {
         let board: Board = set_up_board(3, vec![]);
@@ -61,6 +59,12 @@ pub mod tests {
         assert_eq!(Marker::O, find_current_player(&board));
     }
 
+    #[test]
+    fn o_is_current_player_at_start_of_game_when_o_plays_first() {
+        let board: Board = build_board(3).with_first_player(Marker::O);
+        assert_eq!(Marker::O, find_current_player(&board));
+    }
+
     #[test]
     fn game_not_over_when_board_is_empty() {
         let board: Board = set_up_board(3, vec![]);
@@ -121,16 +125,28 @@ pub mod tests {
         assert!(is_game_tied(&board));
     }
 
+    #[test]
+    fn a_game_is_won_with_fewer_than_a_full_row() {
+        let board = build_board(4)
+            .with_win_length(3)
+            .place_marker(&StandardRules, 5)
+            .place_marker(&StandardRules, 0)
+            .place_marker(&StandardRules, 6)
+            .place_marker(&StandardRules, 1)
+            .place_marker(&StandardRules, 7);
+        assert_eq!(Marker::X, find_winner(&board));
+    }
+
     #[test]
     fn check_line_won_by_x() {
         let line: Vec<String> = vec!["X".to_string(), "X".to_string(), "X".to_string()];
-        assert!(is_line_won_by(&line, &Marker::X));
+        assert!(StandardRules.is_line_won_by(&line, &Marker::X, &build_board(3)));
     }
 
     #[test]
     fn check_row_not_won_by_o() {
         let line: Vec<String> = vec!["O".to_string(), " ".to_string(), "X".to_string()];
-        assert!(!is_line_won_by(&line, &Marker::O));
+        assert!(!StandardRules.is_line_won_by(&line, &Marker::O, &build_board(3)));
     }
 
     #[test]
@@ -150,4 +166,130 @@ pub mod tests {
         let board = set_up_board(3, vec![0, 8, 4, 7, 2, 6]);
         assert_eq!(Marker::O, find_winner(&board));
     }
+
+    #[test]
+    fn find_winner_when_x_completes_a_line_in_misere() {
+        let board = set_up_board(3, vec![0, 4, 8, 2, 6, 3, 7]).with_variant(Variant::Misere);
+        assert_eq!(Marker::O, find_winner(&board));
+    }
+
+    #[test]
+    fn find_winner_when_nobody_completes_a_line_in_misere() {
+        let board = set_up_board(3, vec![0, 4, 8, 2, 6, 7, 1, 3, 5]).with_variant(Variant::Misere);
+        assert_eq!(Marker::NA, find_winner(&board));
+    }
+
+    #[test]
+    fn find_winner_when_o_completes_a_line_of_x_in_wild() {
+        let board = build_board(3)
+            .with_variant(Variant::Wild)
+            .place_marker(&WildRules, 0)
+            .place_marker(&WildRules, 4)
+            .place_marker(&WildRules, 8)
+            .play(&WildRules, Move {
+                space: 1,
+                marker: Marker::X,
+            })
+            .place_marker(&WildRules, 5)
+            .play(&WildRules, Move {
+                space: 2,
+                marker: Marker::X,
+            });
+        assert_eq!(Marker::O, find_winner(&board));
+    }
+
+    #[test]
+    fn find_winner_of_three_sub_boards_in_a_row_in_ultimate() {
+        let board = set_up_ultimate_board(vec![
+            0, 40, 9, 50, 18, 31, 3, 49, 12, 67, 21, 76, 6, 58, 15, 77, 24,
+        ]);
+        assert_eq!(Marker::X, find_winner(&board));
+        assert!(is_game_over(&board));
+    }
+
+    #[test]
+    fn ultimate_is_not_won_by_a_line_across_sub_boards() {
+        let board = set_up_ultimate_board(vec![2, 40, 3, 50, 4]);
+        assert_eq!(Marker::NA, find_winner(&board));
+        assert!(!is_game_over(&board));
+    }
+
+    #[test]
+    fn finds_the_sum_that_wins_numerical() {
+        let board = build_board(3).with_variant(Variant::Numerical);
+        assert_eq!(15, find_target_sum(&board));
+    }
+
+    #[test]
+    fn find_winner_of_a_line_adding_up_to_15_in_numerical() {
+        let board = build_board(3)
+            .with_variant(Variant::Numerical)
+            .play(&NumericalRules, Move {
+                space: 0,
+                marker: Marker::Number(1),
+            })
+            .play(&NumericalRules, Move {
+                space: 4,
+                marker: Marker::Number(8),
+            })
+            .play(&NumericalRules, Move {
+                space: 8,
+                marker: Marker::Number(7),
+            })
+            .play(&NumericalRules, Move {
+                space: 2,
+                marker: Marker::Number(2),
+            })
+            .play(&NumericalRules, Move {
+                space: 6,
+                marker: Marker::Number(9),
+            });
+        assert_eq!(Marker::NA, find_winner(&board));
+        let board = board.play(&NumericalRules, Move {
+            space: 3,
+            marker: Marker::Number(4),
+        });
+        assert!(!is_game_over(&board));
+        let board = board.play(&NumericalRules, Move {
+            space: 5,
+            marker: Marker::Number(3),
+        });
+        assert_eq!(Marker::X, find_winner(&board));
+    }
+
+    #[test]
+    fn a_game_with_moves_left_is_in_progress() {
+        let board = set_up_board(3, vec![0, 4]);
+        assert_eq!(Outcome::InProgress, find_outcome(&board));
+    }
+
+    #[test]
+    fn a_full_board_without_a_line_is_a_draw() {
+        let board = set_up_board(3, vec![0, 4, 8, 2, 6, 7, 1, 3, 5]);
+        assert_eq!(Outcome::Draw, find_outcome(&board));
+    }
+
+    #[test]
+    fn a_win_records_the_winner_and_the_line() {
+        let board = set_up_board(3, vec![0, 4, 8, 2, 6, 3, 7]);
+        assert_eq!(
+            Outcome::Win {
+                player: Marker::X,
+                line: vec![6, 7, 8],
+            },
+            find_outcome(&board)
+        );
+    }
+
+    #[test]
+    fn a_misere_win_records_the_line_the_loser_completed() {
+        let board = set_up_board(3, vec![0, 3, 1, 4, 2]).with_variant(Variant::Misere);
+        assert_eq!(
+            Outcome::Win {
+                player: Marker::O,
+                line: vec![0, 1, 2],
+            },
+            find_outcome(&board)
+        );
+    }
 }
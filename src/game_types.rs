@@ -1,12 +1,14 @@
 use players::Players;
 use marker::Marker;
+use difficulty::Difficulty;
+use mcts;
 
-pub fn create_players(choice: i32) -> Vec<Players> {
+pub fn create_players(choice: i32, difficulty: Difficulty, threads: usize) -> Vec<Players> {
     match choice {
         1 => human_vs_human(),
-        2 => human_vs_computer(),
-        3 => computer_vs_human(),
-        _ => computer_vs_computer(),
+        2 => human_vs_computer(difficulty, threads),
+        3 => computer_vs_human(difficulty, threads),
+        _ => computer_vs_computer(difficulty, threads),
     }
 }
 
@@ -16,36 +18,112 @@ fn human_vs_human() -> Vec<Players> {
     vec![x, o]
 }
 
-fn human_vs_computer() -> Vec<Players> {
+fn human_vs_computer(difficulty: Difficulty, threads: usize) -> Vec<Players> {
     let x = Players::Human { marker: Marker::X };
-    let o = Players::Computer { marker: Marker::O };
+    let o = Players::Computer {
+        marker: Marker::O,
+        difficulty,
+        threads,
+    };
     vec![x, o]
 }
 
-
-fn computer_vs_human() -> Vec<Players> {
-    let x = Players::Computer { marker: Marker::X };
+fn computer_vs_human(difficulty: Difficulty, threads: usize) -> Vec<Players> {
+    let x = Players::Computer {
+        marker: Marker::X,
+        difficulty,
+        threads,
+    };
     let o = Players::Human { marker: Marker::O };
     vec![x, o]
 }
 
-fn computer_vs_computer() -> Vec<Players> {
-    let x = Players::Computer { marker: Marker::X };
-    let o = Players::Computer { marker: Marker::O };
+fn computer_vs_computer(difficulty: Difficulty, threads: usize) -> Vec<Players> {
+    let x = Players::Computer {
+        marker: Marker::X,
+        difficulty,
+        threads,
+    };
+    let o = Players::Computer {
+        marker: Marker::O,
+        difficulty,
+        threads,
+    };
     vec![x, o]
 }
 
+pub fn switch_to_mcts(
+    players: Vec<Players>,
+    markers: &[Marker],
+    settings: mcts::Settings,
+) -> Vec<Players> {
+    players
+        .into_iter()
+        .map(|player| match player {
+            Players::Computer { marker, .. } if markers.contains(&marker) => {
+                Players::Mcts { marker, settings }
+            }
+            player => player,
+        })
+        .collect()
+}
+
+pub fn switch_to_strategy(players: Vec<Players>, markers: &[Marker]) -> Vec<Players> {
+    players
+        .into_iter()
+        .map(|player| match player {
+            Players::Computer { marker, .. } if markers.contains(&marker) => {
+                Players::Strategy { marker }
+            }
+            player => player,
+        })
+        .collect()
+}
+
 pub mod tests {
     #[cfg(test)]
     use super::*;
     #[cfg(test)]
     use players;
+    #[cfg(test)]
+    use computer::SINGLE_THREAD;
     #[test]
     fn creates_two_players() {
-        let players = create_players(1);
+        let players = create_players(1, Difficulty::Hard, SINGLE_THREAD);
         let x = &players[0];
         let o = &players[1];
         assert_eq!(&Marker::X, players::get_marker(x));
         assert_eq!(&Marker::O, players::get_marker(o));
     }
+
+    #[test]
+    fn switches_only_the_chosen_computer_players_to_mcts() {
+        let players = switch_to_mcts(
+            create_players(4, Difficulty::Hard, SINGLE_THREAD),
+            &[Marker::O],
+            mcts::default_settings(),
+        );
+        match (&players[0], &players[1]) {
+            (&Players::Computer { .. }, &Players::Mcts { .. }) => (),
+            _ => panic!("Expected minimax X and MCTS O"),
+        }
+        let players = switch_to_mcts(
+            create_players(1, Difficulty::Hard, SINGLE_THREAD),
+            &[Marker::X, Marker::O],
+            mcts::default_settings(),
+        );
+        assert!(players.iter().all(players::is_human));
+    }
+
+    #[test]
+    fn switches_only_the_chosen_computer_players_to_the_strategy() {
+        let players = switch_to_strategy(
+            create_players(2, Difficulty::Hard, SINGLE_THREAD),
+            &[Marker::X, Marker::O],
+        );
+        match (&players[0], &players[1]) {
+            (&Players::Human { .. }, &Players::Strategy { .. }) => (),
+            _ => panic!("Expected human X and rule-based O"),
+        }
+    }
 }
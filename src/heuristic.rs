@@ -0,0 +1,119 @@
+use lines;
+use marker;
+use board_formatter;
+use board::Board;
+use marker::Marker;
+
+const NO_ADVANTAGE: i32 = 0;
+const THREAT_WEIGHT: i32 = 10;
+const CENTRE_WEIGHT: i32 = 2;
+const HALF: i32 = 2;
+
+pub fn score_position(board: &Board, lines: &[Vec<String>], player: &Marker) -> i32 {
+    score_lines(lines, player) + score_centre(board, player)
+}
+
+pub fn score_lines(lines: &[Vec<String>], player: &Marker) -> i32 {
+    let own = marker::inspect(player);
+    let opponent = marker::inspect(&marker::opponent(player));
+    lines
+        .iter()
+        .map(|line| {
+            let owned = line.iter().filter(|space| **space == own).count();
+            let opposed = line.iter().filter(|space| **space == opponent).count();
+            match (owned, opposed) {
+                (0, 0) => NO_ADVANTAGE,
+                (_, 0) => score_open_line(owned, line.len()),
+                (0, _) => -score_open_line(opposed, line.len()),
+                _ => NO_ADVANTAGE,
+            }
+        })
+        .sum()
+}
+
+pub fn score_centre(board: &Board, player: &Marker) -> i32 {
+    let markers = board_formatter::expand_board(board);
+    let own = marker::inspect(player);
+    let opponent = marker::inspect(&marker::opponent(player));
+    find_centre_spaces(board)
+        .iter()
+        .map(|space| {
+            let marker = &markers[*space as usize];
+            if *marker == own {
+                CENTRE_WEIGHT
+            } else if *marker == opponent {
+                -CENTRE_WEIGHT
+            } else {
+                NO_ADVANTAGE
+            }
+        })
+        .sum()
+}
+
+pub fn find_centre_spaces(board: &Board) -> Vec<i32> {
+    let size = *board.get_size();
+    let dimensions = *board.get_dimensions();
+    let low = (size - 1) / HALF;
+    let high = size / HALF;
+    (0..size.pow(dimensions as u32))
+        .filter(|space| {
+            lines::find_coordinates(*space, size, dimensions)
+                .iter()
+                .all(|coordinate| *coordinate == low || *coordinate == high)
+        })
+        .collect()
+}
+
+pub fn score_open_line(markers: usize, length: usize) -> i32 {
+    if markers + 1 == length {
+        THREAT_WEIGHT
+    } else {
+        markers as i32
+    }
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+    #[cfg(test)]
+    use board::build_board;
+    #[cfg(test)]
+    use board::tests::set_up_board;
+
+    #[test]
+    fn finds_the_centre_of_odd_and_even_boards() {
+        assert_eq!(vec![4], find_centre_spaces(&build_board(3)));
+        assert_eq!(vec![5, 6, 9, 10], find_centre_spaces(&build_board(4)));
+        assert_eq!(
+            vec![21, 22, 25, 26, 37, 38, 41, 42],
+            find_centre_spaces(&build_board(4).with_dimensions(3))
+        );
+    }
+
+    #[test]
+    fn scores_open_lines_and_threats() {
+        let board = set_up_board(4, vec![0, 15, 1]);
+        let lines = lines::find_all_lines(&board);
+        assert_eq!(-2, score_lines(&lines, &Marker::O));
+        assert_eq!(2, score_lines(&lines, &Marker::X));
+        let board = set_up_board(3, vec![0, 4, 1]);
+        let lines = lines::find_all_lines(&board);
+        assert_eq!(THREAT_WEIGHT + 1 - 2, score_lines(&lines, &Marker::X));
+    }
+
+    #[test]
+    fn blocked_lines_are_worth_nothing() {
+        let lines = vec![vec!["X".to_string(), "O".to_string(), " ".to_string()]];
+        assert_eq!(0, score_lines(&lines, &Marker::X));
+    }
+
+    #[test]
+    fn rewards_holding_the_centre() {
+        let board = set_up_board(3, vec![4, 0]);
+        assert_eq!(CENTRE_WEIGHT, score_centre(&board, &Marker::X));
+        assert_eq!(
+            score_lines(&lines::find_all_lines(&board), &Marker::X) + CENTRE_WEIGHT,
+            score_position(&board, &lines::find_all_lines(&board), &Marker::X)
+        );
+    }
+}
@@ -1,7 +1,21 @@
 use io;
+use tui;
+use action::Action;
+use board::Board;
+use rules::Rules;
 
-const OFFSET: usize = 1;
-
-pub fn find_space() -> i32 {
-    io::select_number() - OFFSET as i32
+pub fn choose_action<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    notice: &str,
+    details: &str,
+) -> Action {
+    if tui::is_available() {
+        tui::select_action(rules, board, notice, details)
+    } else {
+        if !notice.is_empty() {
+            io::display(notice);
+        }
+        io::select_action(board)
+    }
 }
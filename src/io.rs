@@ -1,6 +1,13 @@
 extern crate termion;
 use marker;
+use variant;
 use marker::Marker;
+use action::Action;
+use board::{Board, Move};
+use computer::Evaluation;
+use outcome::Outcome;
+use tactics::Tactic;
+use strategy::Rule;
 use std::io::{self, BufRead};
 
 pub const TITLE: &str = "Tic Tac Toe";
@@ -9,11 +16,22 @@ pub const GAME_TYPE: &str = "Select game type
 2 - Human vs Computer
 3 - Computer vs Human
 4 - Computer vs Computer";
+pub const RULES: &str = "Select rules";
 pub const SELECT_A_SPACE: &str = ", select a space";
 pub const WINNER: &str = " wins the game!";
+pub const DRAW: &str = "The game is a draw!";
+pub const DRAW_AGREED: &str = "The players agree to a draw!";
+pub const RESIGN: &str = "resign";
+pub const DRAW_OFFER: &str = "draw";
 pub const PLAY_AGAIN: &str = "Play again?
 1 - Yes
 2 - No";
+pub const HINT: &str = "?";
+const MOVE_REJECTED: &str = "That move can not be played";
+const VALUE_SEPARATOR: char = '=';
+const COORDINATE_SEPARATORS: &[char] = &[' ', ',', '/', '\n', '\r'];
+
+const OFFSET: i32 = 1;
 
 pub fn display(output: &str) {
     println!("{}", output);
@@ -27,6 +45,73 @@ pub fn select_number() -> i32 {
     }
 }
 
+pub fn select_action(board: &Board) -> Action {
+    let input = get_input();
+    match parse_coordinates(&input, *board.get_size(), *board.get_dimensions())
+        .or_else(|| parse_action(&input))
+    {
+        Some(action) => action,
+        None => select_action(board),
+    }
+}
+
+pub fn parse_coordinates(input: &str, size: i32, dimensions: i32) -> Option<Action> {
+    let coordinates: Vec<i32> = input
+        .split(COORDINATE_SEPARATORS)
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<i32>().unwrap_or(0))
+        .collect();
+    if coordinates.len() != dimensions as usize
+        || coordinates
+            .iter()
+            .any(|coordinate| *coordinate < OFFSET || *coordinate > size)
+    {
+        return None;
+    }
+    Some(Action::Move(coordinates.iter().fold(0, |space, coordinate| {
+        space * size + coordinate - OFFSET
+    })))
+}
+
+pub fn parse_action(input: &str) -> Option<Action> {
+    let input = input.trim();
+    if input == HINT {
+        return Some(Action::Hint);
+    }
+    if input == RESIGN {
+        return Some(Action::Resign);
+    }
+    if input == DRAW_OFFER {
+        return Some(Action::OfferDraw);
+    }
+    if let Some((space, value)) = input.split_once(VALUE_SEPARATOR) {
+        return match (space.trim().parse::<i32>(), value.trim().parse::<i32>()) {
+            (Ok(space), Ok(value)) => Some(Action::Place(Move {
+                space: space - OFFSET,
+                marker: Marker::Number(value),
+            })),
+            _ => None,
+        };
+    }
+    let (number, marker) = split_marker(input);
+    match (number.trim().parse::<i32>(), marker) {
+        (Ok(n), Some(marker)) => Some(Action::Place(Move {
+            space: n - OFFSET,
+            marker,
+        })),
+        (Ok(n), None) => Some(Action::Move(n - OFFSET)),
+        (Err(_e), _) => None,
+    }
+}
+
+fn split_marker(input: &str) -> (&str, Option<Marker>) {
+    match input.chars().last().map(|last| last.to_ascii_uppercase()) {
+        Some('X') => (&input[..input.len() - OFFSET as usize], Some(Marker::X)),
+        Some('O') => (&input[..input.len() - OFFSET as usize], Some(Marker::O)),
+        _ => (input, None),
+    }
+}
+
 pub fn ask_player_type() -> i32 {
     display(GAME_TYPE);
     let selection = select_number();
@@ -37,6 +122,41 @@ pub fn ask_player_type() -> i32 {
     }
 }
 
+pub fn format_rules_menu() -> String {
+    format!("{}\n{}", RULES, variant::format_menu())
+}
+
+pub fn ask_rules() -> i32 {
+    display(&format_rules_menu());
+    let selection = select_number();
+    if (1..=variant::count_choices()).contains(&selection) {
+        selection
+    } else {
+        ask_rules()
+    }
+}
+
+pub fn ask_to_accept_draw(player: &Marker) -> bool {
+    display(&format!(
+        "{} offers a draw. {}, do you accept?\n1 - Yes\n2 - No",
+        marker::inspect(&marker::opponent(player)),
+        marker::inspect(player)
+    ));
+    match select_number() {
+        1 => true,
+        2 => false,
+        _ => ask_to_accept_draw(player),
+    }
+}
+
+pub fn decline_draw(player: &Marker) -> String {
+    format!("{} declines the draw", marker::inspect(player))
+}
+
+pub fn reject_move() -> String {
+    MOVE_REJECTED.to_string()
+}
+
 pub fn ask_play_again() -> i32 {
     display(PLAY_AGAIN);
     let selection = select_number();
@@ -68,12 +188,106 @@ pub fn select_space(player: &Marker) -> String {
     select
 }
 
+pub fn alert_outcome(outcome: &Outcome) -> String {
+    match *outcome {
+        Outcome::Win { ref player, ref line } if line.is_empty() => alert_winner(player),
+        Outcome::Win { ref player, ref line } => format!(
+            "{} (line {})",
+            alert_winner(player),
+            line.iter()
+                .map(|space| (space + OFFSET).to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        Outcome::Draw => DRAW.to_string(),
+        Outcome::Resigned { ref player } => format!(
+            "{} resigns. {}",
+            marker::inspect(player),
+            alert_winner(&marker::opponent(player))
+        ),
+        Outcome::DrawAgreed => DRAW_AGREED.to_string(),
+        Outcome::TimeForfeit { ref player } => format!(
+            "{} runs out of time. {}",
+            marker::inspect(player),
+            alert_winner(&marker::opponent(player))
+        ),
+        Outcome::InProgress => String::new(),
+    }
+}
+
 pub fn alert_winner(player: &Marker) -> String {
     let mut winner: String = marker::inspect(player);
     winner += WINNER;
     winner
 }
 
+pub fn describe_evaluation(player: &Marker, evaluation: &Evaluation) -> String {
+    match *evaluation {
+        Evaluation::Win(moves) => format!("{} wins in {}", marker::inspect(player), moves),
+        Evaluation::Loss(0) => format!("{} wins", marker::inspect(&marker::opponent(player))),
+        Evaluation::Loss(moves) => format!(
+            "{} wins in {}",
+            marker::inspect(&marker::opponent(player)),
+            moves
+        ),
+        Evaluation::Draw => "draw".to_string(),
+        Evaluation::Unknown => "unknown".to_string(),
+    }
+}
+
+pub fn describe_outlook(evaluation: &Evaluation, value: i32) -> String {
+    match *evaluation {
+        Evaluation::Win(moves) => format!("win in {}", moves),
+        Evaluation::Loss(0) => "loss".to_string(),
+        Evaluation::Loss(moves) => format!("loss in {}", moves),
+        Evaluation::Draw => "draw".to_string(),
+        Evaluation::Unknown => format!("heuristic {:+}", value),
+    }
+}
+
+pub fn describe_tactic(tactic: &Tactic) -> &'static str {
+    match *tactic {
+        Tactic::Win => "wins",
+        Tactic::Block => "blocks a threat",
+        Tactic::Fork => "forks",
+    }
+}
+
+pub fn describe_rule(rule: &Rule) -> &'static str {
+    match *rule {
+        Rule::Win => "win",
+        Rule::Block => "block",
+        Rule::Fork => "fork",
+        Rule::BlockFork => "block a fork",
+        Rule::Centre => "take the centre",
+        Rule::OppositeCorner => "take the opposite corner",
+        Rule::EmptyCorner => "take an empty corner",
+        Rule::EmptySide => "take an empty side",
+    }
+}
+
+pub fn suggest_space(space: i32, player: &Marker, evaluation: &Evaluation) -> String {
+    format!(
+        "Hint: try space {} ({})",
+        space + OFFSET,
+        describe_evaluation(player, evaluation)
+    )
+}
+
+pub fn suggest_move(next_move: &Move, player: &Marker, evaluation: &Evaluation) -> String {
+    format!(
+        "Hint: try {} on space {} ({})",
+        marker::inspect(&next_move.marker),
+        next_move.space + OFFSET,
+        describe_evaluation(player, evaluation)
+    )
+}
+
+pub fn count_hints(player: &Marker, hints: usize) -> String {
+    let plural = if hints == 1 { "hint" } else { "hints" };
+    format!("{} used {} {}", marker::inspect(player), hints, plural)
+}
+
 pub fn clear_screen() {
     print!("{}{}", termion::clear::All, termion::cursor::Goto(1, 1));
 }
@@ -107,6 +321,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn asks_user_to_select_rules() {
+        assert_eq!(
+            "Select rules
+1 - Standard
+2 - Misere (completing a line loses)
+3 - Wild (place X or O on each turn)
+4 - Ultimate (nine boards, your space picks the next board)
+5 - Numerical (odd against even numbers, a line adding up to 15 wins)
+6 - Notakto (both place X, whoever completes the last line loses)
+7 - Order and Chaos (6x6, either marker, Order needs five in a row)",
+            format_rules_menu()
+        );
+    }
+
+    #[test]
+    fn describes_the_outlook_of_a_move_for_its_player() {
+        assert_eq!("win in 2", describe_outlook(&Evaluation::Win(2), 250_000));
+        assert_eq!("loss in 1", describe_outlook(&Evaluation::Loss(1), -500_000));
+        assert_eq!("draw", describe_outlook(&Evaluation::Draw, 0));
+        assert_eq!("heuristic +12", describe_outlook(&Evaluation::Unknown, 12));
+        assert_eq!("heuristic -3", describe_outlook(&Evaluation::Unknown, -3));
+    }
+
+    #[test]
+    fn describes_a_move_that_loses_at_once() {
+        assert_eq!("O wins", describe_evaluation(&Marker::X, &Evaluation::Loss(0)));
+    }
+
     #[test]
     fn asks_user_to_play_again() {
         assert_eq!(
@@ -123,10 +366,129 @@ mod tests {
         assert_eq!(turn, select_space(&Marker::X));
     }
 
+    #[test]
+    fn reads_a_space_as_a_move() {
+        assert_eq!(Some(Action::Move(4)), parse_action("5\n"));
+    }
+
+    #[test]
+    fn reads_a_question_mark_as_a_hint() {
+        assert_eq!(Some(Action::Hint), parse_action("?\n"));
+    }
+
+    #[test]
+    fn reads_a_resignation_and_a_draw_offer() {
+        assert_eq!(Some(Action::Resign), parse_action("resign\n"));
+        assert_eq!(Some(Action::OfferDraw), parse_action("draw\n"));
+    }
+
+    #[test]
+    fn tells_the_players_a_draw_was_declined() {
+        assert_eq!("O declines the draw", decline_draw(&Marker::O));
+    }
+
+    #[test]
+    fn reads_a_space_and_a_marker_as_a_placement() {
+        let placement = Move {
+            space: 4,
+            marker: Marker::O,
+        };
+        assert_eq!(Some(Action::Place(placement)), parse_action("5o\n"));
+        assert_eq!(Some(Action::Place(placement)), parse_action("5 O\n"));
+    }
+
+    #[test]
+    fn reads_layer_row_and_column_coordinates() {
+        assert_eq!(Some(Action::Move(27)), parse_coordinates("2 3 4\n", 4, 3));
+        assert_eq!(Some(Action::Move(0)), parse_coordinates("1,1,1", 4, 3));
+        assert_eq!(Some(Action::Move(63)), parse_coordinates("4/4/4", 4, 3));
+    }
+
+    #[test]
+    fn ignores_coordinates_off_the_board() {
+        assert_eq!(None, parse_coordinates("2 5 1", 4, 3));
+        assert_eq!(None, parse_coordinates("2 3", 4, 3));
+        assert_eq!(None, parse_coordinates("27\n", 4, 3));
+    }
+
+    #[test]
+    fn reads_a_space_and_a_number_as_a_placement() {
+        let placement = Move {
+            space: 4,
+            marker: Marker::Number(7),
+        };
+        assert_eq!(Some(Action::Place(placement)), parse_action("5=7\n"));
+        assert_eq!(Some(Action::Place(placement)), parse_action("5 = 7\n"));
+        assert_eq!(None, parse_action("5=\n"));
+    }
+
+    #[test]
+    fn ignores_other_input() {
+        assert_eq!(None, parse_action("five\n"));
+    }
+
+    #[test]
+    fn suggests_a_space() {
+        assert_eq!(
+            "Hint: try space 3 (X wins in 1)",
+            suggest_space(2, &Marker::X, &Evaluation::Win(1))
+        );
+        assert_eq!(
+            "Hint: try space 5 (O wins in 2)",
+            suggest_space(4, &Marker::X, &Evaluation::Loss(2))
+        );
+        assert_eq!(
+            "Hint: try space 1 (draw)",
+            suggest_space(0, &Marker::O, &Evaluation::Draw)
+        );
+    }
+
+    #[test]
+    fn suggests_a_space_and_a_marker() {
+        let next_move = Move {
+            space: 1,
+            marker: Marker::O,
+        };
+        assert_eq!(
+            "Hint: try O on space 2 (X wins in 2)",
+            suggest_move(&next_move, &Marker::X, &Evaluation::Win(2))
+        );
+    }
+
+    #[test]
+    fn counts_the_hints_used() {
+        assert_eq!("X used 1 hint", count_hints(&Marker::X, 1));
+        assert_eq!("O used 3 hints", count_hints(&Marker::O, 3));
+    }
+
     #[test]
     fn alerts_user_of_the_winner() {
         let winner: String = "X wins the game!".to_string();
         assert_eq!(winner, alert_winner(&Marker::X));
     }
 
+    #[test]
+    fn alerts_user_of_the_outcome() {
+        let outcome = Outcome::Win {
+            player: Marker::O,
+            line: vec![2, 4, 6],
+        };
+        assert_eq!("O wins the game! (line 3, 5, 7)", alert_outcome(&outcome));
+        let outcome = Outcome::Win {
+            player: Marker::X,
+            line: vec![],
+        };
+        assert_eq!("X wins the game!", alert_outcome(&outcome));
+        assert_eq!("The game is a draw!", alert_outcome(&Outcome::Draw));
+        assert_eq!(
+            "X resigns. O wins the game!",
+            alert_outcome(&Outcome::Resigned { player: Marker::X })
+        );
+        assert_eq!("The players agree to a draw!", alert_outcome(&Outcome::DrawAgreed));
+        assert_eq!(
+            "O runs out of time. X wins the game!",
+            alert_outcome(&Outcome::TimeForfeit { player: Marker::O })
+        );
+    }
+
 }
@@ -2,6 +2,8 @@ use board_formatter;
 use board::Board;
 
 const OFFSET: usize = 1;
+const FLAT: i32 = 2;
+const STEPS: [i32; 3] = [-1, 0, 1];
 
 pub fn split_board_into_rows(expanded_board: &[String], size: i32) -> Vec<Vec<String>> {
     let chunks = expanded_board.chunks(size as usize);
@@ -40,21 +42,110 @@ pub fn find_right_diagonal(rows: &[Vec<String>]) -> Vec<String> {
 }
 
 pub fn find_all_lines(board: &Board) -> Vec<Vec<String>> {
+    if *board.get_dimensions() != FLAT {
+        let expanded_board = board_formatter::expand_board(board);
+        return find_line_spaces(*board.get_size(), *board.get_dimensions(), *board.get_win_length())
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|space| expanded_board[*space as usize].to_string())
+                    .collect()
+            })
+            .collect();
+    }
     let mut winning_scenarios: Vec<Vec<String>> = Vec::new();
-    let mut rows = split_board_into_rows(
+    let length = *board.get_win_length() as usize;
+    let rows = split_board_into_rows(
         &board_formatter::expand_board(board),
         board.get_size().abs(),
     );
-    let mut columns = find_columns(&rows);
-    let left = find_left_diagonal(&rows);
-    let right = find_right_diagonal(&rows);
-    winning_scenarios.append(&mut rows);
-    winning_scenarios.append(&mut columns);
-    winning_scenarios.push(left);
-    winning_scenarios.push(right);
+    let columns = find_columns(&rows);
+    let squares = find_squares(&rows, length);
+    winning_scenarios.append(&mut find_segments(&rows, length));
+    winning_scenarios.append(&mut find_segments(&columns, length));
+    for square in &squares {
+        winning_scenarios.push(find_left_diagonal(square));
+    }
+    for square in &squares {
+        winning_scenarios.push(find_right_diagonal(square));
+    }
     winning_scenarios
 }
 
+pub fn find_line_spaces(size: i32, dimensions: i32, length: i32) -> Vec<Vec<i32>> {
+    let mut lines: Vec<Vec<i32>> = Vec::new();
+    for direction in find_directions(dimensions) {
+        let stride = find_space(&direction, size);
+        for start in 0..size.pow(dimensions as u32) {
+            let fits = find_coordinates(start, size, dimensions)
+                .iter()
+                .zip(&direction)
+                .all(|(coordinate, change)| {
+                    let end = coordinate + change * (length - OFFSET as i32);
+                    end >= 0 && end < size
+                });
+            if fits {
+                lines.push((0..length).map(|step| start + stride * step).collect());
+            }
+        }
+    }
+    lines
+}
+
+pub fn find_directions(dimensions: i32) -> Vec<Vec<i32>> {
+    let mut directions: Vec<Vec<i32>> = vec![vec![]];
+    for _dimension in 0..dimensions {
+        directions = directions
+            .iter()
+            .flat_map(|direction| {
+                STEPS.iter().map(move |step| {
+                    let mut longer = direction.clone();
+                    longer.push(*step);
+                    longer
+                })
+            })
+            .collect();
+    }
+    directions
+        .into_iter()
+        .filter(|direction| direction.iter().find(|step| **step != 0) == Some(&1))
+        .collect()
+}
+
+pub fn find_coordinates(space: i32, size: i32, dimensions: i32) -> Vec<i32> {
+    (0..dimensions)
+        .rev()
+        .map(|dimension| space / size.pow(dimension as u32) % size)
+        .collect()
+}
+
+fn find_space(coordinates: &[i32], size: i32) -> i32 {
+    coordinates
+        .iter()
+        .fold(0, |space, coordinate| space * size + coordinate)
+}
+
+pub fn find_segments(lines: &[Vec<String>], length: usize) -> Vec<Vec<String>> {
+    lines
+        .iter()
+        .flat_map(|line| line.windows(length).map(|segment| segment.to_vec()))
+        .collect()
+}
+
+pub fn find_squares(rows: &[Vec<String>], length: usize) -> Vec<Vec<Vec<String>>> {
+    let mut squares: Vec<Vec<Vec<String>>> = Vec::new();
+    for top in 0..(rows.len() + OFFSET).saturating_sub(length) {
+        for left in 0..(rows.len() + OFFSET).saturating_sub(length) {
+            let square = rows[top..top + length]
+                .iter()
+                .map(|row| row[left..left + length].to_vec())
+                .collect();
+            squares.push(square);
+        }
+    }
+    squares
+}
+
 pub mod tests {
     #[cfg(test)]
     use super::*;
@@ -167,6 +258,45 @@ pub mod tests {
         assert_eq!(diagonal, find_right_diagonal(&rows));
     }
 
+    #[test]
+    fn finds_segments_of_a_line() {
+        let lines: Vec<Vec<String>> = vec![vec![
+            "X".to_string(),
+            "O".to_string(),
+            "X".to_string(),
+            "O".to_string(),
+        ]];
+        let segments: Vec<Vec<String>> = vec![
+            vec!["X".to_string(), "O".to_string(), "X".to_string()],
+            vec!["O".to_string(), "X".to_string(), "O".to_string()],
+        ];
+        assert_eq!(segments, find_segments(&lines, 3));
+    }
+
+    #[test]
+    fn finds_squares_within_rows() {
+        let rows: Vec<Vec<String>> = vec![
+            vec!["X".to_string(), "X".to_string(), "O".to_string()],
+            vec!["O".to_string(), "O".to_string(), "X".to_string()],
+            vec!["X".to_string(), "O".to_string(), "X".to_string()],
+        ];
+        let squares = find_squares(&rows, 2);
+        assert_eq!(4, squares.len());
+        assert_eq!(
+            vec![
+                vec!["O".to_string(), "X".to_string()],
+                vec!["O".to_string(), "X".to_string()],
+            ],
+            squares[3]
+        );
+    }
+
+    #[test]
+    fn finds_winning_scenarios_shorter_than_a_row() {
+        let board = set_up_board(4, vec![]).with_win_length(3);
+        assert_eq!(24, find_all_lines(&board).len());
+    }
+
     #[test]
     fn finds_winning_scenarios() {
         let board = set_up_board(3, vec![0, 4, 8, 2, 6, 7, 1, 3, 5]);
@@ -183,4 +313,36 @@ pub mod tests {
         assert_eq!(winning_scenarios, find_all_lines(&board));
     }
 
+    #[test]
+    fn finds_one_direction_for_each_pair_of_opposites() {
+        assert_eq!(4, find_directions(2).len());
+        assert_eq!(13, find_directions(3).len());
+    }
+
+    #[test]
+    fn finds_the_coordinates_of_a_space() {
+        assert_eq!(vec![1, 2, 3], find_coordinates(27, 4, 3));
+        assert_eq!(vec![2, 1], find_coordinates(7, 3, 2));
+    }
+
+    #[test]
+    fn finds_the_lines_of_a_flat_board_by_direction() {
+        assert_eq!(8, find_line_spaces(3, 2, 3).len());
+        assert!(find_line_spaces(3, 2, 3).contains(&vec![2, 4, 6]));
+    }
+
+    #[test]
+    fn finds_all_76_lines_of_a_4x4x4_cube() {
+        let lines = find_line_spaces(4, 3, 4);
+        assert_eq!(76, lines.len());
+        assert!(lines.contains(&vec![0, 21, 42, 63]));
+        assert!(lines.contains(&vec![3, 6, 9, 12]));
+        assert!(lines.contains(&vec![5, 21, 37, 53]));
+    }
+
+    #[test]
+    fn finds_winning_scenarios_in_a_cube() {
+        let board = set_up_board(4, vec![]).with_dimensions(3);
+        assert_eq!(76, find_all_lines(&board).len());
+    }
 }
@@ -10,7 +10,62 @@ mod computer;
 mod human;
 mod board_formatter;
 mod lines;
+mod tui;
+mod cli;
+mod difficulty;
+mod random;
+mod report;
+mod analysis;
+mod action;
+mod variant;
+mod ultimate;
+mod rules;
+mod notakto;
+mod order_and_chaos;
+mod outcome;
+mod clock;
+mod heuristic;
+mod mcts;
+mod solved;
+mod symmetry;
+mod zobrist;
+mod perft;
+mod tactics;
+mod strategy;
+mod reasoning;
+
+use std::env;
+use std::process;
+
+const USAGE_ERROR: i32 = 2;
 
 fn main() {
-    app_runner::start();
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    match cli::parse(&arguments) {
+        Ok(cli::Command::Play(options)) => app_runner::start(&options),
+        Ok(cli::Command::Analyze(position, options)) => {
+            match analysis::analyse_position(&position, &options) {
+                Ok(analysis) => io::display(&analysis),
+                Err(message) => {
+                    eprintln!("{}", message);
+                    process::exit(USAGE_ERROR);
+                }
+            }
+        }
+        Ok(cli::Command::Perft(position, options)) => {
+            match perft::enumerate_position(&position, &options) {
+                Ok(counts) => io::display(&counts),
+                Err(message) => {
+                    eprintln!("{}", message);
+                    process::exit(USAGE_ERROR);
+                }
+            }
+        }
+        Ok(cli::Command::Solve) => io::display(&solved::generate_table()),
+        Ok(cli::Command::Help) => io::display(cli::USAGE),
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            process::exit(USAGE_ERROR);
+        }
+    }
 }
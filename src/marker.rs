@@ -1,8 +1,10 @@
-#[derive(PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
 pub enum Marker {
+    #[default]
     X,
     O,
     NA,
+    Number(i32),
 }
 
 pub fn inspect(marker: &Marker) -> String {
@@ -10,6 +12,15 @@ pub fn inspect(marker: &Marker) -> String {
         Marker::X => "X".to_string(),
         Marker::O => "O".to_string(),
         Marker::NA => "Nobody".to_string(),
+        Marker::Number(value) => value.to_string(),
+    }
+}
+
+pub fn opponent(marker: &Marker) -> Marker {
+    match *marker {
+        Marker::X => Marker::O,
+        Marker::O => Marker::X,
+        Marker::NA | Marker::Number(_) => Marker::NA,
     }
 }
 
@@ -28,4 +39,15 @@ pub mod tests {
         let marker = Marker::O;
         assert_eq!("O", inspect(&marker));
     }
+
+    #[test]
+    fn has_numbers_as_markers() {
+        assert_eq!("7", inspect(&Marker::Number(7)));
+    }
+
+    #[test]
+    fn o_is_the_opponent_of_x() {
+        assert_eq!(Marker::O, opponent(&Marker::X));
+        assert_eq!(Marker::X, opponent(&Marker::O));
+    }
 }
@@ -0,0 +1,312 @@
+use outcome;
+use rules::Rules;
+use board::{Board, Move};
+use computer::Budget;
+use marker::Marker;
+use outcome::Outcome;
+use random::Random;
+use std::cmp::Reverse;
+use std::time::Instant;
+
+pub const DEFAULT_ITERATIONS: u64 = 1000;
+pub const DEFAULT_EXPLORATION: f64 = 1.41;
+const ROOT: usize = 0;
+const WIN: f64 = 1.0;
+const DRAW: f64 = 0.5;
+const LOSS: f64 = 0.0;
+const DRAWN_MARGIN: f64 = 0.1;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Settings {
+    pub iterations: u64,
+    pub exploration: f64,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Statistics {
+    pub next_move: Move,
+    pub visits: u32,
+    pub win_rate: f64,
+}
+
+struct Node {
+    board: Board,
+    next_move: Option<Move>,
+    mover: Marker,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried: Vec<Move>,
+    visits: u32,
+    reward: f64,
+}
+
+pub fn default_settings() -> Settings {
+    Settings {
+        iterations: DEFAULT_ITERATIONS,
+        exploration: DEFAULT_EXPLORATION,
+    }
+}
+
+pub fn choose_move<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    settings: &Settings,
+    random: &mut Random,
+    budget: Option<Budget>,
+) -> (Move, Vec<Statistics>) {
+    let tree = search(rules, board, settings, random, budget);
+    let best_child = find_most_visited_child(&tree);
+    let next_move = tree[best_child].next_move.expect("Only the root has no move");
+    (next_move, collect_statistics(&tree))
+}
+
+pub fn accepts_draw<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    settings: &Settings,
+    random: &mut Random,
+    budget: Option<Budget>,
+) -> bool {
+    if rules.is_game_over(board) {
+        return true;
+    }
+    let tree = search(rules, board, settings, random, budget);
+    let best_child = &tree[find_most_visited_child(&tree)];
+    (best_child.reward / f64::from(best_child.visits) - DRAW).abs() < DRAWN_MARGIN
+}
+
+fn search<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    settings: &Settings,
+    random: &mut Random,
+    budget: Option<Budget>,
+) -> Vec<Node> {
+    let (iterations, deadline) = match budget {
+        Some(Budget::Time(time)) => (settings.iterations, Some(Instant::now() + time)),
+        Some(Budget::Nodes(nodes)) => (nodes, None),
+        None => (settings.iterations, None),
+    };
+    let mut tree = vec![build_node(rules, board.clone(), None, Marker::NA, None)];
+    for _ in 0..iterations {
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) && tree.len() > 1 {
+            break;
+        }
+        let selected = select(&tree, settings.exploration);
+        let expanded = expand(rules, &mut tree, selected, random);
+        let outcome = simulate(rules, &tree[expanded].board, random);
+        backpropagate(&mut tree, expanded, &outcome);
+    }
+    tree
+}
+
+fn build_node<R: Rules + ?Sized>(
+    rules: &R,
+    board: Board,
+    next_move: Option<Move>,
+    mover: Marker,
+    parent: Option<usize>,
+) -> Node {
+    let untried = if rules.is_game_over(&board) {
+        Vec::new()
+    } else {
+        rules.find_legal_moves(&board)
+    };
+    Node {
+        board,
+        next_move,
+        mover,
+        parent,
+        children: Vec::new(),
+        untried,
+        visits: 0,
+        reward: LOSS,
+    }
+}
+
+fn select(tree: &[Node], exploration: f64) -> usize {
+    let mut current = ROOT;
+    while tree[current].untried.is_empty() && !tree[current].children.is_empty() {
+        let parent_visits = f64::from(tree[current].visits);
+        current = *tree[current]
+            .children
+            .iter()
+            .max_by(|first, second| {
+                score_child(&tree[**first], parent_visits, exploration)
+                    .total_cmp(&score_child(&tree[**second], parent_visits, exploration))
+                    .then(second.cmp(first))
+            })
+            .expect("A node with children has a best child");
+    }
+    current
+}
+
+fn score_child(child: &Node, parent_visits: f64, exploration: f64) -> f64 {
+    let visits = f64::from(child.visits);
+    child.reward / visits + exploration * (parent_visits.ln() / visits).sqrt()
+}
+
+fn expand<R: Rules + ?Sized>(
+    rules: &R,
+    tree: &mut Vec<Node>,
+    parent: usize,
+    random: &mut Random,
+) -> usize {
+    if tree[parent].untried.is_empty() {
+        return parent;
+    }
+    let index = random.below(tree[parent].untried.len());
+    let next_move = tree[parent].untried.swap_remove(index);
+    let mover = rules.find_current_player(&tree[parent].board);
+    let board = tree[parent].board.clone().play(rules, next_move);
+    let child = build_node(rules, board, Some(next_move), mover, Some(parent));
+    tree.push(child);
+    let child_index = tree.len() - 1;
+    tree[parent].children.push(child_index);
+    child_index
+}
+
+fn simulate<R: Rules + ?Sized>(rules: &R, board: &Board, random: &mut Random) -> Outcome {
+    let mut board = board.clone();
+    while !rules.is_game_over(&board) {
+        let moves = rules.find_legal_moves(&board);
+        board = board.play(rules, moves[random.below(moves.len())]);
+    }
+    rules.find_outcome(&board)
+}
+
+fn backpropagate(tree: &mut [Node], leaf: usize, outcome: &Outcome) {
+    let mut current = Some(leaf);
+    while let Some(index) = current {
+        let node = &mut tree[index];
+        node.visits += 1;
+        node.reward += if outcome::is_won_by(outcome, &node.mover) {
+            WIN
+        } else if outcome::is_drawn(outcome) {
+            DRAW
+        } else {
+            LOSS
+        };
+        current = node.parent;
+    }
+}
+
+fn collect_statistics(tree: &[Node]) -> Vec<Statistics> {
+    let mut statistics: Vec<Statistics> = tree[ROOT]
+        .children
+        .iter()
+        .map(|child| Statistics {
+            next_move: tree[*child].next_move.expect("Only the root has no move"),
+            visits: tree[*child].visits,
+            win_rate: tree[*child].reward / f64::from(tree[*child].visits),
+        })
+        .collect();
+    statistics.sort_by_key(|move_statistics| Reverse(move_statistics.visits));
+    statistics
+}
+
+fn find_most_visited_child(tree: &[Node]) -> usize {
+    *tree[ROOT]
+        .children
+        .iter()
+        .max_by(|first, second| {
+            tree[**first]
+                .visits
+                .cmp(&tree[**second].visits)
+                .then(second.cmp(first))
+        })
+        .expect("The search expands at least one move")
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+    #[cfg(test)]
+    use board::tests::set_up_board;
+    #[cfg(test)]
+    use random::build_random;
+    #[cfg(test)]
+    use rules::find_rules;
+    #[cfg(test)]
+    use ultimate::tests::set_up_ultimate_board;
+    #[cfg(test)]
+    use ultimate::UltimateRules;
+
+    #[cfg(test)]
+    fn find_space(board: &Board, seed: u64) -> i32 {
+        let mut random = build_random(seed);
+        choose_move(find_rules(board), board, &default_settings(), &mut random, None).0.space
+    }
+
+    #[test]
+    fn takes_a_winning_space() {
+        let board = set_up_board(3, vec![0, 4, 1, 6]);
+        assert_eq!(2, find_space(&board, 1));
+    }
+
+    #[test]
+    fn blocks_a_win() {
+        let board = set_up_board(3, vec![0, 4, 1]);
+        assert_eq!(2, find_space(&board, 2));
+    }
+
+    #[test]
+    fn the_same_seed_chooses_the_same_move() {
+        let board = set_up_board(4, vec![5]);
+        assert_eq!(find_space(&board, 7), find_space(&board, 7));
+    }
+
+    #[test]
+    fn plays_a_legal_move_in_ultimate() {
+        let board = set_up_ultimate_board(vec![40]);
+        let settings = Settings {
+            iterations: 20,
+            exploration: DEFAULT_EXPLORATION,
+        };
+        let (next_move, _) =
+            choose_move(find_rules(&board), &board, &settings, &mut build_random(3), None);
+        assert!(UltimateRules.is_move_legal(&board, &next_move));
+    }
+
+    #[test]
+    fn reports_the_visits_of_each_move_most_visited_first() {
+        let board = set_up_board(3, vec![0, 4, 1]);
+        let mut random = build_random(2);
+        let (next_move, statistics) =
+            choose_move(find_rules(&board), &board, &default_settings(), &mut random, None);
+        assert_eq!(next_move, statistics[0].next_move);
+        let visits: u32 = statistics.iter().map(|move_statistics| move_statistics.visits).sum();
+        assert_eq!(DEFAULT_ITERATIONS, u64::from(visits));
+        assert!(statistics[0].win_rate > statistics[statistics.len() - 1].win_rate);
+    }
+
+    #[test]
+    fn explores_every_move_before_repeating_one() {
+        let board = set_up_board(3, vec![0, 4]);
+        let settings = Settings {
+            iterations: 7,
+            exploration: DEFAULT_EXPLORATION,
+        };
+        let tree = search(find_rules(&board), &board, &settings, &mut build_random(5), None);
+        assert_eq!(7, tree[ROOT].children.len());
+        assert!(tree[ROOT].untried.is_empty());
+    }
+
+    #[test]
+    fn accepts_a_draw_only_when_the_game_looks_even() {
+        let mut random = build_random(4);
+        let board = set_up_board(3, vec![4, 0, 8, 2, 1, 7, 6, 3]);
+        assert!(accepts_draw(find_rules(&board), &board, &default_settings(), &mut random, None));
+        let board = set_up_board(3, vec![0, 4, 1, 6]);
+        assert!(!accepts_draw(find_rules(&board), &board, &default_settings(), &mut random, None));
+    }
+
+    #[test]
+    fn simulates_as_many_games_as_the_node_budget() {
+        let board = set_up_board(3, vec![0, 4]);
+        let budget = Some(Budget::Nodes(50));
+        let tree =
+            search(find_rules(&board), &board, &default_settings(), &mut build_random(6), budget);
+        assert_eq!(50, tree[ROOT].visits);
+    }
+}
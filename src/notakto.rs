@@ -0,0 +1,223 @@
+use game;
+use board;
+use lines;
+use board_printer;
+use board::{Board, Move};
+use marker::Marker;
+use rules::Rules;
+use variant::Variant;
+
+pub const NOTAKTO_SIZE: i32 = 3;
+const SPACES_PER_BOARD: i32 = NOTAKTO_SIZE * NOTAKTO_SIZE;
+const SINGLE_BOARD: i32 = 1;
+const NOTAKTO_SEARCH_DEPTH: i32 = 4;
+const FLAT: i32 = 2;
+const PARITY_WEIGHT: i32 = 10;
+const LAST_BOARD: i32 = 1;
+
+pub fn build_board(boards: i32, first_player: Marker) -> Board {
+    board::build_board(NOTAKTO_SIZE)
+        .with_boards(boards)
+        .with_first_player(first_player)
+        .with_variant(Variant::Notakto)
+}
+
+pub fn find_sub_board(space: i32) -> i32 {
+    space / SPACES_PER_BOARD
+}
+
+pub fn find_cell(space: i32) -> i32 {
+    space % SPACES_PER_BOARD
+}
+
+pub fn build_sub_boards(board: &Board) -> Vec<Board> {
+    (0..*board.get_boards())
+        .map(|sub_board| {
+            let moves = board
+                .get_spaces()
+                .iter()
+                .zip(board.get_markers())
+                .filter(|&(space, _marker)| find_sub_board(*space) == sub_board)
+                .map(|(space, marker)| Move {
+                    space: find_cell(*space),
+                    marker: *marker,
+                })
+                .collect();
+            board::build_board(NOTAKTO_SIZE).with_moves(moves)
+        })
+        .collect()
+}
+
+pub fn is_dead(sub_board: &Board) -> bool {
+    game::find_winner(sub_board) != Marker::NA
+}
+
+pub fn find_room(sub_board: &Board) -> i32 {
+    let lines = lines::find_line_spaces(NOTAKTO_SIZE, FLAT, NOTAKTO_SIZE);
+    find_room_from(&mut sub_board.get_spaces().clone(), &lines, 0)
+}
+
+fn find_room_from(taken: &mut Vec<i32>, lines: &[Vec<i32>], first: i32) -> i32 {
+    let mut room = 0;
+    for space in first..SPACES_PER_BOARD {
+        if taken.contains(&space) {
+            continue;
+        }
+        taken.push(space);
+        let completes_a_line = lines
+            .iter()
+            .any(|line| line.contains(&space) && line.iter().all(|cell| taken.contains(cell)));
+        if !completes_a_line {
+            room = room.max(1 + find_room_from(taken, lines, space + 1));
+        }
+        taken.pop();
+    }
+    room
+}
+
+pub fn find_playable_spaces(board: &Board) -> Vec<i32> {
+    let live_sub_boards: Vec<bool> = build_sub_boards(board)
+        .iter()
+        .map(|sub_board| !is_dead(sub_board))
+        .collect();
+    (0..board.get_number_of_spaces())
+        .filter(|space| {
+            board.is_space_available(space) && live_sub_boards[find_sub_board(*space) as usize]
+        })
+        .collect()
+}
+
+pub struct NotaktoRules;
+
+impl Rules for NotaktoRules {
+    fn set_up_board(&self, board: Board, boards: i32) -> Board {
+        build_board(boards, *board.get_first_player())
+    }
+
+    fn find_default_marker(&self, _board: &Board) -> Marker {
+        Marker::X
+    }
+
+    fn find_playable_spaces(&self, board: &Board) -> Vec<i32> {
+        find_playable_spaces(board)
+    }
+
+    fn find_playable_markers(&self, _board: &Board) -> Vec<Marker> {
+        vec![Marker::X]
+    }
+
+    fn is_game_over(&self, board: &Board) -> bool {
+        self.find_playable_spaces(board).is_empty()
+    }
+
+    fn is_game_tied(&self, _board: &Board) -> bool {
+        false
+    }
+
+    fn find_winning_line(&self, _board: &Board) -> Vec<i32> {
+        Vec::new()
+    }
+
+    fn find_winner(&self, board: &Board) -> Marker {
+        if self.is_game_over(board) {
+            self.find_current_player(board)
+        } else {
+            Marker::NA
+        }
+    }
+
+    fn find_heuristic_score(&self, board: &Board) -> i32 {
+        let live_sub_boards: Vec<Board> = build_sub_boards(board)
+            .into_iter()
+            .filter(|sub_board| !is_dead(sub_board))
+            .collect();
+        let room: i32 = live_sub_boards.iter().map(find_room).sum();
+        let moves_before_the_last_board = room + live_sub_boards.len() as i32 - LAST_BOARD;
+        if moves_before_the_last_board % 2 == 1 {
+            PARITY_WEIGHT
+        } else {
+            -PARITY_WEIGHT
+        }
+    }
+
+    fn find_search_depth(&self, board: &Board) -> Option<i32> {
+        if *board.get_boards() > SINGLE_BOARD {
+            Some(NOTAKTO_SEARCH_DEPTH)
+        } else {
+            None
+        }
+    }
+
+    fn find_grid_label(&self) -> &'static str {
+        board_printer::BOARD
+    }
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+
+    #[cfg(test)]
+    pub fn set_up_notakto_board(boards: i32, spaces: Vec<i32>) -> Board {
+        spaces
+            .iter()
+            .fold(build_board(boards, Marker::X), |board, space| {
+                board.place_marker(&NotaktoRules, *space)
+            })
+    }
+
+    #[test]
+    fn finds_the_sub_board_and_cell_of_a_space() {
+        assert_eq!((0, 4), (find_sub_board(4), find_cell(4)));
+        assert_eq!((1, 0), (find_sub_board(9), find_cell(9)));
+        assert_eq!((2, 8), (find_sub_board(26), find_cell(26)));
+    }
+
+    #[test]
+    fn both_players_place_x() {
+        let board = set_up_notakto_board(2, vec![4, 13]);
+        assert_eq!(&vec![Marker::X, Marker::X], board.get_markers());
+        assert_eq!(Marker::X, NotaktoRules.find_current_player(&board));
+    }
+
+    #[test]
+    fn a_board_with_three_in_a_row_is_dead() {
+        let board = set_up_notakto_board(2, vec![0, 1, 2]);
+        let sub_boards = build_sub_boards(&board);
+        assert!(is_dead(&sub_boards[0]));
+        assert!(!is_dead(&sub_boards[1]));
+        assert_eq!((9..18).collect::<Vec<i32>>(), find_playable_spaces(&board));
+    }
+
+    #[test]
+    fn rejects_a_move_on_a_dead_board() {
+        let board = set_up_notakto_board(2, vec![0, 1, 2, 3]);
+        assert_eq!(&vec![0, 1, 2], board.get_spaces());
+    }
+
+    #[test]
+    fn finds_the_markers_a_board_can_take_without_dying() {
+        let board = set_up_notakto_board(2, vec![0, 1, 13]);
+        let sub_boards = build_sub_boards(&board);
+        assert_eq!(4, find_room(&sub_boards[0]));
+        assert_eq!(4, find_room(&sub_boards[1]));
+        assert_eq!(6, find_room(&board::build_board(NOTAKTO_SIZE)));
+    }
+
+    #[test]
+    fn favours_leaving_an_even_number_of_moves_before_the_last_board() {
+        let board = set_up_notakto_board(2, vec![0, 1, 13]);
+        assert!(NotaktoRules.find_heuristic_score(&board) > 0);
+        let board = board.place_marker(&NotaktoRules, 2);
+        assert!(NotaktoRules.find_heuristic_score(&board) < 0);
+    }
+
+    #[test]
+    fn the_player_who_kills_the_last_board_loses() {
+        let board = set_up_notakto_board(2, vec![0, 1, 2, 9]);
+        assert!(!game::is_game_over(&board));
+        let board = board.place_marker(&NotaktoRules, 10).place_marker(&NotaktoRules, 11);
+        assert!(game::is_game_over(&board));
+        assert_eq!(Marker::X, game::find_winner(&board));
+    }
+}
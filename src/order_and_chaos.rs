@@ -0,0 +1,177 @@
+use board;
+use rules;
+use marker;
+use heuristic;
+use board::{Board, Move};
+use marker::Marker;
+use rules::Rules;
+use variant::Variant;
+
+pub const ORDER_AND_CHAOS_SIZE: i32 = 6;
+const WIN_LENGTH: i32 = 5;
+const ORDER_AND_CHAOS_SEARCH_DEPTH: i32 = 2;
+const BLOCKED_WEIGHT: i32 = 1;
+
+pub fn build_board(first_player: Marker) -> Board {
+    board::build_board(ORDER_AND_CHAOS_SIZE)
+        .with_win_length(WIN_LENGTH)
+        .with_first_player(first_player)
+        .with_variant(Variant::OrderAndChaos)
+}
+
+pub fn find_order(board: &Board) -> Marker {
+    *board.get_first_player()
+}
+
+pub fn score_line_for_order(line: &[String]) -> i32 {
+    let count = |player: &Marker| {
+        line.iter()
+            .filter(|space| **space == marker::inspect(player))
+            .count()
+    };
+    match (count(&Marker::X), count(&Marker::O)) {
+        (0, 0) => rules::NO_ADVANTAGE,
+        (markers, 0) | (0, markers) => heuristic::score_open_line(markers, line.len()),
+        _ => -BLOCKED_WEIGHT,
+    }
+}
+
+pub struct OrderAndChaosRules;
+
+impl Rules for OrderAndChaosRules {
+    fn set_up_board(&self, board: Board, _boards: i32) -> Board {
+        build_board(*board.get_first_player())
+    }
+
+    fn find_playable_markers(&self, _board: &Board) -> Vec<Marker> {
+        vec![Marker::X, Marker::O]
+    }
+
+    fn chooses_markers(&self) -> bool {
+        true
+    }
+
+    fn is_game_tied(&self, _board: &Board) -> bool {
+        false
+    }
+
+    fn find_winner(&self, board: &Board) -> Marker {
+        let order = find_order(board);
+        if self.find_line_completer(board) != Marker::NA {
+            order
+        } else if self.find_playable_spaces(board).is_empty() {
+            marker::opponent(&order)
+        } else {
+            Marker::NA
+        }
+    }
+
+    fn find_heuristic_score(&self, board: &Board) -> i32 {
+        let score: i32 = self
+            .find_lines(board)
+            .iter()
+            .map(|line| score_line_for_order(line))
+            .sum();
+        if self.find_current_player(board) == find_order(board) {
+            score
+        } else {
+            -score
+        }
+    }
+
+    fn find_search_depth(&self, _board: &Board) -> Option<i32> {
+        Some(ORDER_AND_CHAOS_SEARCH_DEPTH)
+    }
+
+    fn format_move(&self, next_move: &Move) -> String {
+        rules::format_placed_move(next_move)
+    }
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+    #[cfg(test)]
+    use game;
+
+    #[cfg(test)]
+    pub fn set_up_order_and_chaos_board(moves: Vec<(i32, Marker)>) -> Board {
+        moves
+            .iter()
+            .fold(build_board(Marker::X), |board, &(space, marker)| {
+                board.play(&OrderAndChaosRules, Move { space, marker })
+            })
+    }
+
+    #[test]
+    fn plays_five_in_a_row_on_a_6_by_6_board() {
+        let board = build_board(Marker::X);
+        assert_eq!(36, board.get_number_of_spaces());
+        assert_eq!(&5, board.get_win_length());
+        assert_eq!(72, OrderAndChaosRules.find_legal_moves(&board).len());
+    }
+
+    #[test]
+    fn order_is_the_first_player() {
+        assert_eq!(Marker::O, find_order(&build_board(Marker::O)));
+    }
+
+    #[test]
+    fn order_wins_with_five_of_either_marker_in_a_row() {
+        let board = set_up_order_and_chaos_board(vec![
+            (0, Marker::O),
+            (6, Marker::X),
+            (1, Marker::O),
+            (7, Marker::X),
+            (2, Marker::O),
+            (8, Marker::X),
+            (3, Marker::O),
+            (9, Marker::X),
+        ]);
+        assert!(!game::is_game_over(&board));
+        let board = board.play(&OrderAndChaosRules, Move {
+            space: 4,
+            marker: Marker::O,
+        });
+        assert_eq!(Marker::X, game::find_winner(&board));
+    }
+
+    #[test]
+    fn order_counts_open_lines_and_chaos_counts_blocked_lines() {
+        let line = |markers: &[&str]| -> Vec<String> {
+            markers.iter().map(|marker| marker.to_string()).collect()
+        };
+        assert_eq!(2, score_line_for_order(&line(&["X", "X", " ", " ", " "])));
+        assert_eq!(2, score_line_for_order(&line(&["O", " ", "O", " ", " "])));
+        assert_eq!(-BLOCKED_WEIGHT, score_line_for_order(&line(&["X", "O", " ", " ", " "])));
+    }
+
+    #[test]
+    fn order_loses_ground_when_chaos_blocks_its_lines() {
+        let open = set_up_order_and_chaos_board(vec![(7, Marker::X), (5, Marker::O)]);
+        let blocked = set_up_order_and_chaos_board(vec![(7, Marker::X), (8, Marker::O)]);
+        assert!(
+            OrderAndChaosRules.find_heuristic_score(&open)
+                > OrderAndChaosRules.find_heuristic_score(&blocked)
+        );
+    }
+
+    #[test]
+    fn chaos_wins_when_the_board_fills_without_five_in_a_row() {
+        let board = set_up_order_and_chaos_board(
+            (0..36)
+                .map(|space| {
+                    let row = space / ORDER_AND_CHAOS_SIZE;
+                    let marker = if (space + row / 2) % 2 == 0 {
+                        Marker::X
+                    } else {
+                        Marker::O
+                    };
+                    (space, marker)
+                })
+                .collect(),
+        );
+        assert!(game::is_game_over(&board));
+        assert_eq!(Marker::O, game::find_winner(&board));
+    }
+}
@@ -0,0 +1,73 @@
+use marker;
+use marker::Marker;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Outcome {
+    InProgress,
+    Win { player: Marker, line: Vec<i32> },
+    Draw,
+    Resigned { player: Marker },
+    DrawAgreed,
+    TimeForfeit { player: Marker },
+}
+
+pub fn is_won_by(outcome: &Outcome, player: &Marker) -> bool {
+    match *outcome {
+        Outcome::Win { player: winner, .. } => &winner == player,
+        Outcome::Resigned { player: loser } | Outcome::TimeForfeit { player: loser } => {
+            &marker::opponent(&loser) == player
+        }
+        _ => false,
+    }
+}
+
+pub fn is_over(outcome: &Outcome) -> bool {
+    *outcome != Outcome::InProgress
+}
+
+pub fn is_drawn(outcome: &Outcome) -> bool {
+    *outcome == Outcome::Draw || *outcome == Outcome::DrawAgreed
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+
+    #[test]
+    fn a_win_is_won_by_its_player_only() {
+        let outcome = Outcome::Win {
+            player: Marker::X,
+            line: vec![0, 4, 8],
+        };
+        assert!(is_won_by(&outcome, &Marker::X));
+        assert!(!is_won_by(&outcome, &Marker::O));
+        assert!(!is_won_by(&Outcome::Draw, &Marker::X));
+    }
+
+    #[test]
+    fn a_resignation_is_won_by_the_opponent() {
+        let outcome = Outcome::Resigned { player: Marker::X };
+        assert!(is_won_by(&outcome, &Marker::O));
+        assert!(!is_won_by(&outcome, &Marker::X));
+    }
+
+    #[test]
+    fn running_out_of_time_is_won_by_the_opponent() {
+        let outcome = Outcome::TimeForfeit { player: Marker::O };
+        assert!(is_won_by(&outcome, &Marker::X));
+        assert!(!is_drawn(&outcome));
+    }
+
+    #[test]
+    fn an_agreed_draw_is_drawn() {
+        assert!(is_drawn(&Outcome::DrawAgreed));
+        assert!(is_drawn(&Outcome::Draw));
+        assert!(!is_drawn(&Outcome::Resigned { player: Marker::O }));
+    }
+
+    #[test]
+    fn only_a_game_in_progress_is_not_over() {
+        assert!(!is_over(&Outcome::InProgress));
+        assert!(is_over(&Outcome::Draw));
+    }
+}
@@ -0,0 +1,243 @@
+use rules;
+use analysis;
+use board::Board;
+use rules::Rules;
+use cli::{Format, Options};
+use marker::Marker;
+use std::collections::HashSet;
+
+const FIRST_PLY: usize = 1;
+
+struct Ply {
+    positions: u64,
+    x_wins: u64,
+    o_wins: u64,
+    draws: u64,
+    unique_positions: HashSet<u64>,
+}
+
+pub fn enumerate_position(position: &str, options: &Options) -> Result<String, String> {
+    let board = analysis::parse_position(position, options)?;
+    let plies = count_positions(board, options.depth);
+    Ok(match options.format {
+        Format::Text => format_plies(&plies),
+        Format::Json => format_plies_json(&plies),
+    })
+}
+
+fn count_positions(board: Board, depth: Option<usize>) -> Vec<Ply> {
+    let mut plies = Vec::new();
+    if !rules::find_rules(&board).is_game_over(&board) {
+        visit(board, FIRST_PLY, depth, &mut plies);
+    }
+    plies
+}
+
+fn visit(board: Board, ply: usize, depth: Option<usize>, plies: &mut Vec<Ply>) -> Board {
+    if depth.is_some_and(|depth| ply > depth) {
+        return board;
+    }
+    if plies.len() < ply {
+        plies.push(build_ply());
+    }
+    let rules = rules::find_rules(&board);
+    let mut board = board;
+    for next_move in rules.find_legal_moves(&board) {
+        board = board.play(rules, next_move);
+        if !record(rules, &board, &mut plies[ply - FIRST_PLY]) {
+            board = visit(board, ply + 1, depth, plies);
+        }
+        board = board.undo();
+    }
+    board
+}
+
+fn build_ply() -> Ply {
+    Ply {
+        positions: 0,
+        x_wins: 0,
+        o_wins: 0,
+        draws: 0,
+        unique_positions: HashSet::new(),
+    }
+}
+
+fn record(rules: &dyn Rules, board: &Board, ply: &mut Ply) -> bool {
+    ply.positions += 1;
+    ply.unique_positions.insert(board.find_canonical_key());
+    let is_game_over = rules.is_game_over(board);
+    if is_game_over {
+        match rules.find_winner(board) {
+            Marker::X => ply.x_wins += 1,
+            Marker::O => ply.o_wins += 1,
+            _ => ply.draws += 1,
+        }
+    }
+    is_game_over
+}
+
+fn count_games(plies: &[Ply]) -> u64 {
+    plies
+        .iter()
+        .map(|ply| ply.x_wins + ply.o_wins + ply.draws)
+        .sum()
+}
+
+fn format_plies(plies: &[Ply]) -> String {
+    let mut table = format_row("Ply", "Positions", "Unique", "X wins", "O wins", "Draws");
+    for (index, ply) in plies.iter().enumerate() {
+        table += &format_row(
+            &(index + FIRST_PLY).to_string(),
+            &ply.positions.to_string(),
+            &ply.unique_positions.len().to_string(),
+            &ply.x_wins.to_string(),
+            &ply.o_wins.to_string(),
+            &ply.draws.to_string(),
+        );
+    }
+    table += &format_row(
+        "Total",
+        &plies.iter().map(|ply| ply.positions).sum::<u64>().to_string(),
+        &plies
+            .iter()
+            .map(|ply| ply.unique_positions.len())
+            .sum::<usize>()
+            .to_string(),
+        &plies.iter().map(|ply| ply.x_wins).sum::<u64>().to_string(),
+        &plies.iter().map(|ply| ply.o_wins).sum::<u64>().to_string(),
+        &plies.iter().map(|ply| ply.draws).sum::<u64>().to_string(),
+    );
+    table + &format!("{} games", count_games(plies))
+}
+
+fn format_row(
+    ply: &str,
+    positions: &str,
+    unique: &str,
+    x_wins: &str,
+    o_wins: &str,
+    draws: &str,
+) -> String {
+    format!(
+        "{:<5} {:>10} {:>8} {:>8} {:>8} {:>8}\n",
+        ply, positions, unique, x_wins, o_wins, draws
+    )
+}
+
+fn format_plies_json(plies: &[Ply]) -> String {
+    let rows: Vec<String> = plies
+        .iter()
+        .enumerate()
+        .map(|(index, ply)| {
+            format!(
+                "{{\"ply\":{},\"positions\":{},\"unique\":{},\"x_wins\":{},\
+                 \"o_wins\":{},\"draws\":{}}}",
+                index + FIRST_PLY,
+                ply.positions,
+                ply.unique_positions.len(),
+                ply.x_wins,
+                ply.o_wins,
+                ply.draws
+            )
+        })
+        .collect();
+    format!(
+        "{{\"plies\":[{}],\"games\":{}}}",
+        rows.join(","),
+        count_games(plies)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use board::build_board;
+    use board::tests::set_up_board;
+    use cli::default_options;
+    use notakto;
+    use variant::Variant;
+
+    #[test]
+    fn counts_the_opening_plies_of_tic_tac_toe() {
+        let plies = count_positions(build_board(3), Some(5));
+        let positions: Vec<u64> = plies.iter().map(|ply| ply.positions).collect();
+        assert_eq!(vec![9, 72, 504, 3024, 15120], positions);
+        assert_eq!(1440, plies[4].x_wins);
+    }
+
+    #[test]
+    fn counts_every_game_after_taking_the_centre() {
+        let plies = count_positions(set_up_board(3, vec![4]), None);
+        assert_eq!(25872, count_games(&plies));
+    }
+
+    #[test]
+    fn counts_positions_that_are_the_same_up_to_symmetry_once() {
+        let plies = count_positions(build_board(3), Some(3));
+        let unique: Vec<usize> = plies.iter().map(|ply| ply.unique_positions.len()).collect();
+        assert_eq!(vec![3, 12, 38], unique);
+    }
+
+    #[test]
+    fn stops_at_the_depth_given() {
+        let plies = count_positions(build_board(4), Some(2));
+        assert_eq!(2, plies.len());
+        assert_eq!(16 * 15, plies[1].positions);
+        assert_eq!(0, count_games(&plies));
+    }
+
+    #[test]
+    fn counts_the_games_left_from_a_position() {
+        let plies = count_positions(set_up_board(3, vec![0, 3, 1, 4]), None);
+        assert_eq!(5, plies[0].positions);
+        assert_eq!(1, plies[0].x_wins);
+        assert_eq!(0, plies[0].o_wins);
+    }
+
+    #[test]
+    fn counts_both_markers_in_wild() {
+        let board = set_up_board(3, vec![0, 1, 2, 4, 3, 5, 7]).with_variant(Variant::Wild);
+        assert_eq!(4, count_positions(board, None)[0].positions);
+    }
+
+    #[test]
+    fn a_finished_game_has_no_plies() {
+        assert!(count_positions(set_up_board(3, vec![0, 3, 1, 4, 2]), None).is_empty());
+    }
+
+    #[test]
+    fn counts_notakto_where_every_game_has_a_winner() {
+        let plies = count_positions(notakto::build_board(1, Marker::X), None);
+        assert_eq!(0, plies.iter().map(|ply| ply.draws).sum::<u64>());
+        assert!(count_games(&plies) > 0);
+    }
+
+    #[test]
+    fn formats_a_table_of_plies() {
+        let options = Options {
+            depth: Some(1),
+            ..default_options()
+        };
+        assert_eq!(
+            "Ply    Positions   Unique   X wins   O wins    Draws\n\
+             1              9        3        0        0        0\n\
+             Total          9        3        0        0        0\n\
+             0 games",
+            enumerate_position("", &options).unwrap()
+        );
+    }
+
+    #[test]
+    fn formats_the_plies_as_json() {
+        let options = Options {
+            depth: Some(1),
+            format: Format::Json,
+            ..default_options()
+        };
+        assert_eq!(
+            "{\"plies\":[{\"ply\":1,\"positions\":9,\"unique\":3,\"x_wins\":0,\"o_wins\":0,\
+             \"draws\":0}],\"games\":0}",
+            enumerate_position("", &options).unwrap()
+        );
+    }
+}
@@ -1,24 +1,91 @@
 use computer;
+use mcts;
+use strategy;
+use io;
 use human;
 use marker::Marker;
 use board::Board;
+use difficulty::Difficulty;
+use random::Random;
+use action::Action;
+use rules::Rules;
+use computer::Budget;
+use reasoning::Reasoning;
 
 pub enum Players {
     Human { marker: Marker },
-    Computer { marker: Marker },
+    Computer {
+        marker: Marker,
+        difficulty: Difficulty,
+        threads: usize,
+    },
+    Mcts { marker: Marker, settings: mcts::Settings },
+    Strategy { marker: Marker },
 }
 
 pub fn get_marker(player: &Players) -> &Marker {
     match *player {
-        Players::Human { ref marker } | Players::Computer { ref marker } => marker,
+        Players::Human { ref marker }
+        | Players::Computer { ref marker, .. }
+        | Players::Mcts { ref marker, .. }
+        | Players::Strategy { ref marker } => marker,
     }
 }
 
-#[allow(unused)]
-pub fn choose_space(player: &Players, board: &Board) -> i32 {
+pub fn is_human(player: &Players) -> bool {
     match *player {
-        Players::Human { ref marker } => human::find_space(),
-        Players::Computer { ref marker } => computer::find_space(board),
+        Players::Human { .. } => true,
+        Players::Computer { .. } | Players::Mcts { .. } | Players::Strategy { .. } => false,
+    }
+}
+
+pub fn choose_action<R: Rules + ?Sized>(
+    rules: &R,
+    player: &Players,
+    board: &Board,
+    random: &mut Random,
+    notice: &str,
+    details: &str,
+    budget: Option<Budget>,
+) -> (Action, Option<Reasoning>) {
+    match *player {
+        Players::Human { .. } => (human::choose_action(rules, board, notice, details), None),
+        Players::Computer {
+            ref difficulty,
+            threads,
+            ..
+        } => {
+            let (next_move, scores) =
+                computer::choose_move(rules, board, difficulty, random, budget, threads);
+            (Action::Place(next_move), Some(Reasoning::Scores(scores)))
+        }
+        Players::Mcts { ref settings, .. } => {
+            let (next_move, statistics) = mcts::choose_move(rules, board, settings, random, budget);
+            (Action::Place(next_move), Some(Reasoning::Visits(statistics)))
+        }
+        Players::Strategy { .. } => {
+            let (rule, next_move) = strategy::choose_rule(rules, board);
+            (Action::Place(next_move), Some(Reasoning::Rule(rule)))
+        }
+    }
+}
+
+pub fn accepts_draw<R: Rules + ?Sized>(
+    rules: &R,
+    player: &Players,
+    board: &Board,
+    random: &mut Random,
+    budget: Option<Budget>,
+) -> bool {
+    match *player {
+        Players::Human { ref marker } => io::ask_to_accept_draw(marker),
+        Players::Computer { threads, .. } => {
+            computer::accepts_draw(rules, board, budget, threads)
+        }
+        Players::Mcts { ref settings, .. } => {
+            mcts::accepts_draw(rules, board, settings, random, budget)
+        }
+        Players::Strategy { ref marker } => strategy::accepts_draw(rules, board, marker, budget),
     }
 }
 
@@ -30,11 +97,34 @@ pub mod tests {
     fn creates_a_human_player() {
         let player = Players::Human { marker: Marker::X };
         assert_eq!(&Marker::X, get_marker(&player));
+        assert!(is_human(&player));
     }
 
     #[test]
     fn creates_a_computer_player() {
-        let player = Players::Computer { marker: Marker::O };
+        let player = Players::Computer {
+            marker: Marker::O,
+            difficulty: Difficulty::Hard,
+            threads: computer::SINGLE_THREAD,
+        };
         assert_eq!(&Marker::O, get_marker(&player));
+        assert!(!is_human(&player));
+    }
+
+    #[test]
+    fn creates_a_rule_based_player() {
+        let player = Players::Strategy { marker: Marker::O };
+        assert_eq!(&Marker::O, get_marker(&player));
+        assert!(!is_human(&player));
+    }
+
+    #[test]
+    fn creates_a_monte_carlo_player() {
+        let player = Players::Mcts {
+            marker: Marker::X,
+            settings: mcts::default_settings(),
+        };
+        assert_eq!(&Marker::X, get_marker(&player));
+        assert!(!is_human(&player));
     }
 }
@@ -0,0 +1,76 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+const FIRST_MIX: u64 = 0xBF58_476D_1CE4_E5B9;
+const SECOND_MIX: u64 = 0x94D0_49BB_1331_11EB;
+const PERCENT: u64 = 100;
+
+pub fn build_random(seed: u64) -> Random {
+    Random { state: seed }
+}
+
+pub fn seed_from_clock() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() ^ u64::from(elapsed.subsec_nanos()),
+        Err(_e) => 0,
+    }
+}
+
+#[derive(Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn next_number(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        let mut mixed = self.state;
+        mixed = (mixed ^ (mixed >> 30)).wrapping_mul(FIRST_MIX);
+        mixed = (mixed ^ (mixed >> 27)).wrapping_mul(SECOND_MIX);
+        mixed ^ (mixed >> 31)
+    }
+
+    pub fn below(&mut self, limit: usize) -> usize {
+        (self.next_number() % limit as u64) as usize
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_number() % PERCENT < percent
+    }
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+
+    #[test]
+    fn the_same_seed_gives_the_same_numbers() {
+        let mut first = build_random(7);
+        let mut second = build_random(7);
+        assert_eq!(first.next_number(), second.next_number());
+        assert_eq!(first.next_number(), second.next_number());
+    }
+
+    #[test]
+    fn different_seeds_give_different_numbers() {
+        let mut first = build_random(7);
+        let mut second = build_random(8);
+        assert_ne!(first.next_number(), second.next_number());
+    }
+
+    #[test]
+    fn picks_a_number_below_the_limit() {
+        let mut random = build_random(0);
+        for _ in 0..100 {
+            assert!(random.below(9) < 9);
+        }
+    }
+
+    #[test]
+    fn never_happens_with_no_chance() {
+        let mut random = build_random(0);
+        for _ in 0..100 {
+            assert!(!random.chance(0));
+        }
+    }
+}
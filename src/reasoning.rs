@@ -0,0 +1,12 @@
+use board::Move;
+use computer::Score;
+use mcts::Statistics;
+use strategy::Rule;
+use std::collections::HashMap;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Reasoning {
+    Scores(HashMap<Move, Score>),
+    Visits(Vec<Statistics>),
+    Rule(Rule),
+}
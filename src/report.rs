@@ -0,0 +1,203 @@
+use marker;
+use outcome;
+use marker::Marker;
+use outcome::Outcome;
+use board::{Board, Move};
+use cli::Format;
+use rules::Rules;
+
+const OFFSET: i32 = 1;
+
+pub fn format_game_json<R: Rules + ?Sized>(
+    rules: &R,
+    number: usize,
+    board: &Board,
+    outcome: &Outcome,
+) -> String {
+    let moves: Vec<String> = board
+        .get_spaces()
+        .iter()
+        .zip(board.get_markers())
+        .map(|(space, marker)| {
+            let next_move = rules.format_move(&Move {
+                space: *space,
+                marker: *marker,
+            });
+            if rules.chooses_markers() {
+                format!("\"{}\"", next_move)
+            } else {
+                next_move
+            }
+        })
+        .collect();
+    format!(
+        "{{\"game\":{},{},\"moves\":[{}]}}",
+        number,
+        format_outcome_json(outcome),
+        moves.join(",")
+    )
+}
+
+fn format_outcome_json(outcome: &Outcome) -> String {
+    match *outcome {
+        Outcome::Win { ref player, ref line } if !line.is_empty() => format!(
+            "\"winner\":\"{}\",\"line\":[{}]",
+            marker::inspect(player),
+            line.iter()
+                .map(|space| (space + OFFSET).to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        ),
+        Outcome::Win { ref player, .. } => format!("\"winner\":\"{}\"", marker::inspect(player)),
+        Outcome::Resigned { ref player } => format!(
+            "\"winner\":\"{}\",\"resigned\":\"{}\"",
+            marker::inspect(&marker::opponent(player)),
+            marker::inspect(player)
+        ),
+        Outcome::TimeForfeit { ref player } => format!(
+            "\"winner\":\"{}\",\"timed_out\":\"{}\"",
+            marker::inspect(&marker::opponent(player)),
+            marker::inspect(player)
+        ),
+        Outcome::DrawAgreed => "\"winner\":null,\"agreed\":true".to_string(),
+        Outcome::Draw | Outcome::InProgress => "\"winner\":null".to_string(),
+    }
+}
+
+pub fn format_summary(format: Format, outcomes: &[Outcome]) -> String {
+    let x_wins = count_wins(outcomes, &Marker::X);
+    let o_wins = count_wins(outcomes, &Marker::O);
+    let draws = outcomes
+        .iter()
+        .filter(|outcome| outcome::is_drawn(outcome))
+        .count();
+    match format {
+        Format::Text => format!(
+            "Games played: {}\nX wins: {}\nO wins: {}\nDraws: {}",
+            outcomes.len(),
+            x_wins,
+            o_wins,
+            draws
+        ),
+        Format::Json => format!(
+            "{{\"games\":{},\"x_wins\":{},\"o_wins\":{},\"draws\":{}}}",
+            outcomes.len(),
+            x_wins,
+            o_wins,
+            draws
+        ),
+    }
+}
+
+fn count_wins(outcomes: &[Outcome], player: &Marker) -> usize {
+    outcomes
+        .iter()
+        .filter(|outcome| outcome::is_won_by(outcome, player))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use board::build_board;
+    use board::tests::set_up_board;
+    use rules;
+    use rules::{NumericalRules, StandardRules, WildRules};
+    use variant::Variant;
+
+    fn find_outcome(board: &Board) -> Outcome {
+        rules::find_rules(board).find_outcome(board)
+    }
+
+    #[test]
+    fn reports_a_won_game_as_json() {
+        let board = set_up_board(3, vec![0, 4, 8, 2, 6, 3, 7]);
+        assert_eq!(
+            "{\"game\":1,\"winner\":\"X\",\"line\":[7,8,9],\"moves\":[1,5,9,3,7,4,8]}",
+            format_game_json(&StandardRules, 1, &board, &find_outcome(&board))
+        );
+    }
+
+    #[test]
+    fn reports_a_tied_game_as_json() {
+        let board = set_up_board(3, vec![0, 4, 8, 2, 6, 7, 1, 3, 5]);
+        assert_eq!(
+            "{\"game\":2,\"winner\":null,\"moves\":[1,5,9,3,7,8,2,4,6]}",
+            format_game_json(&StandardRules, 2, &board, &find_outcome(&board))
+        );
+    }
+
+    #[test]
+    fn reports_a_resignation_as_json() {
+        let board = set_up_board(3, vec![4]);
+        assert_eq!(
+            "{\"game\":3,\"winner\":\"X\",\"resigned\":\"O\",\"moves\":[5]}",
+            format_game_json(&StandardRules, 3, &board, &Outcome::Resigned { player: Marker::O })
+        );
+    }
+
+    #[test]
+    fn reports_a_player_running_out_of_time_as_json() {
+        let board = set_up_board(3, vec![4, 0]);
+        assert_eq!(
+            "{\"game\":5,\"winner\":\"O\",\"timed_out\":\"X\",\"moves\":[5,1]}",
+            format_game_json(&StandardRules, 5, &board, &Outcome::TimeForfeit { player: Marker::X })
+        );
+    }
+
+    #[test]
+    fn reports_an_agreed_draw_as_json() {
+        let board = set_up_board(3, vec![4, 0]);
+        assert_eq!(
+            "{\"game\":4,\"winner\":null,\"agreed\":true,\"moves\":[5,1]}",
+            format_game_json(&StandardRules, 4, &board, &Outcome::DrawAgreed)
+        );
+    }
+
+    #[test]
+    fn reports_the_marker_of_each_move_in_wild_as_json() {
+        let board = build_board(3)
+            .with_variant(Variant::Wild)
+            .play(&WildRules, Move { space: 4, marker: Marker::O })
+            .play(&WildRules, Move { space: 0, marker: Marker::X });
+        assert_eq!(
+            "{\"game\":1,\"winner\":null,\"moves\":[\"5o\",\"1x\"]}",
+            format_game_json(&WildRules, 1, &board, &Outcome::InProgress)
+        );
+    }
+
+    #[test]
+    fn reports_the_number_of_each_move_in_numerical_as_json() {
+        let board = build_board(3)
+            .with_variant(Variant::Numerical)
+            .play(&NumericalRules, Move { space: 4, marker: Marker::Number(5) })
+            .play(&NumericalRules, Move { space: 0, marker: Marker::Number(8) });
+        assert_eq!(
+            "{\"game\":1,\"winner\":null,\"moves\":[\"5=5\",\"1=8\"]}",
+            format_game_json(&NumericalRules, 1, &board, &Outcome::InProgress)
+        );
+    }
+
+    #[test]
+    fn summarises_games_as_text() {
+        let outcomes = vec![
+            find_outcome(&set_up_board(3, vec![0, 4, 8, 2, 6, 3, 7])),
+            find_outcome(&set_up_board(3, vec![0, 4, 8, 2, 6, 7, 1, 3, 5])),
+            Outcome::Resigned { player: Marker::X },
+            Outcome::DrawAgreed,
+        ];
+        assert_eq!(
+            "Games played: 4\nX wins: 1\nO wins: 1\nDraws: 2",
+            format_summary(Format::Text, &outcomes)
+        );
+    }
+
+    #[test]
+    fn summarises_games_as_json() {
+        let outcomes = vec![find_outcome(&set_up_board(3, vec![0, 8, 4, 7, 2, 6]))];
+        assert_eq!(
+            "{\"games\":1,\"x_wins\":0,\"o_wins\":1,\"draws\":0}",
+            format_summary(Format::Json, &outcomes)
+        );
+    }
+}
@@ -0,0 +1,361 @@
+use lines;
+use marker;
+use board_formatter;
+use board_printer;
+use heuristic;
+use solved;
+use marker::Marker;
+use outcome::Outcome;
+use board::{Board, Move};
+use solved::Solution;
+use variant;
+
+const FLAT: i32 = 2;
+const FIRST_ODD: i32 = 1;
+const FIRST_EVEN: i32 = 2;
+const NUMBER_STEP: usize = 2;
+const CUBE_SEARCH_DEPTH: i32 = 2;
+const NUMERICAL_SEARCH_DEPTH: i32 = 4;
+const SINGLE_BOARD: i32 = 1;
+pub const NO_ADVANTAGE: i32 = 0;
+const OFFSET: i32 = 1;
+const VALUE_PREFIX: &str = "=";
+
+pub trait Rules: Sync {
+    fn set_up_board(&self, board: Board, _boards: i32) -> Board {
+        board
+    }
+
+    fn uses_last_move(&self) -> bool {
+        false
+    }
+
+    fn find_current_player(&self, board: &Board) -> Marker {
+        if board.get_spaces().len() % 2 == 0 {
+            *board.get_first_player()
+        } else {
+            marker::opponent(board.get_first_player())
+        }
+    }
+
+    fn find_default_marker(&self, board: &Board) -> Marker {
+        self.find_current_player(board)
+    }
+
+    fn find_playable_spaces(&self, board: &Board) -> Vec<i32> {
+        (0..board.get_number_of_spaces())
+            .filter(|space| board.is_space_available(space))
+            .collect()
+    }
+
+    fn find_playable_markers(&self, board: &Board) -> Vec<Marker> {
+        vec![self.find_current_player(board)]
+    }
+
+    fn find_legal_moves(&self, board: &Board) -> Vec<Move> {
+        let markers = self.find_playable_markers(board);
+        self.find_playable_spaces(board)
+            .iter()
+            .flat_map(|space| {
+                markers.iter().map(move |marker| Move {
+                    space: *space,
+                    marker: *marker,
+                })
+            })
+            .collect()
+    }
+
+    fn is_move_legal(&self, board: &Board, next_move: &Move) -> bool {
+        self.find_playable_markers(board).contains(&next_move.marker)
+            && self.find_playable_spaces(board).contains(&next_move.space)
+    }
+
+    fn chooses_markers(&self) -> bool {
+        false
+    }
+
+    fn has_lines_to_complete(&self, _board: &Board) -> bool {
+        false
+    }
+
+    fn find_lines(&self, board: &Board) -> Vec<Vec<String>> {
+        lines::find_all_lines(board)
+    }
+
+    fn is_line_won_by(&self, line: &[String], player: &Marker, _board: &Board) -> bool {
+        line.iter().all(|space| space == &marker::inspect(player))
+    }
+
+    fn find_line_completer(&self, board: &Board) -> Marker {
+        let winning_scenarios = self.find_lines(board);
+        for player in &[Marker::X, Marker::O] {
+            if winning_scenarios
+                .iter()
+                .any(|line| self.is_line_won_by(line, player, board))
+            {
+                return *player;
+            }
+        }
+        Marker::NA
+    }
+
+    fn is_game_over(&self, board: &Board) -> bool {
+        self.find_line_completer(board) != Marker::NA
+            || self.find_playable_spaces(board).is_empty()
+    }
+
+    fn is_game_tied(&self, board: &Board) -> bool {
+        self.find_line_completer(board) == Marker::NA
+            && self.find_playable_spaces(board).is_empty()
+    }
+
+    fn find_winner(&self, board: &Board) -> Marker {
+        self.find_line_completer(board)
+    }
+
+    fn find_winning_line(&self, board: &Board) -> Vec<i32> {
+        let completer = self.find_line_completer(board);
+        if completer == Marker::NA {
+            return Vec::new();
+        }
+        let markers = board_formatter::expand_board(board);
+        let size = *board.get_size();
+        lines::find_line_spaces(size, *board.get_dimensions(), *board.get_win_length())
+            .into_iter()
+            .find(|line| {
+                let line_markers: Vec<String> = line
+                    .iter()
+                    .map(|space| markers[*space as usize].clone())
+                    .collect();
+                self.is_line_won_by(&line_markers, &completer, board)
+            })
+            .unwrap_or_default()
+    }
+
+    fn find_outcome(&self, board: &Board) -> Outcome {
+        if !self.is_game_over(board) {
+            Outcome::InProgress
+        } else if self.is_game_tied(board) {
+            Outcome::Draw
+        } else {
+            Outcome::Win {
+                player: self.find_winner(board),
+                line: self.find_winning_line(board),
+            }
+        }
+    }
+
+    fn find_heuristic_score(&self, board: &Board) -> i32 {
+        heuristic::score_position(board, &self.find_lines(board), &self.find_current_player(board))
+    }
+
+    fn find_search_depth(&self, board: &Board) -> Option<i32> {
+        if *board.get_dimensions() > FLAT {
+            Some(CUBE_SEARCH_DEPTH)
+        } else {
+            None
+        }
+    }
+
+    fn find_solution(&self, _board: &Board) -> Option<Solution> {
+        None
+    }
+
+    fn format_move(&self, next_move: &Move) -> String {
+        (next_move.space + OFFSET).to_string()
+    }
+
+    fn format_value(&self, value: &str) -> String {
+        value.to_string()
+    }
+
+    fn find_grid_label(&self) -> &'static str {
+        board_printer::LAYER
+    }
+
+    fn format_board(&self, board: &Board) -> String {
+        board_printer::format_board(self, board)
+    }
+}
+
+pub struct StandardRules;
+
+pub struct MisereRules;
+
+pub struct WildRules;
+
+pub struct NumericalRules;
+
+impl Rules for StandardRules {
+    fn has_lines_to_complete(&self, board: &Board) -> bool {
+        *board.get_boards() == SINGLE_BOARD
+    }
+
+    fn find_solution(&self, board: &Board) -> Option<Solution> {
+        solved::find_solution(board)
+    }
+}
+
+impl Rules for MisereRules {
+    fn find_winner(&self, board: &Board) -> Marker {
+        marker::opponent(&self.find_line_completer(board))
+    }
+
+    fn find_heuristic_score(&self, board: &Board) -> i32 {
+        -heuristic::score_lines(&self.find_lines(board), &self.find_current_player(board))
+    }
+}
+
+impl Rules for WildRules {
+    fn find_playable_markers(&self, _board: &Board) -> Vec<Marker> {
+        vec![Marker::X, Marker::O]
+    }
+
+    fn chooses_markers(&self) -> bool {
+        true
+    }
+
+    fn format_move(&self, next_move: &Move) -> String {
+        format_placed_move(next_move)
+    }
+
+    fn find_winner(&self, board: &Board) -> Marker {
+        if self.find_line_completer(board) == Marker::NA {
+            Marker::NA
+        } else {
+            marker::opponent(&self.find_current_player(board))
+        }
+    }
+
+    fn find_heuristic_score(&self, _board: &Board) -> i32 {
+        NO_ADVANTAGE
+    }
+}
+
+impl Rules for NumericalRules {
+    fn find_playable_markers(&self, board: &Board) -> Vec<Marker> {
+        let first_value = if board.get_spaces().len() % 2 == 0 {
+            FIRST_ODD
+        } else {
+            FIRST_EVEN
+        };
+        (first_value..=board.get_number_of_spaces())
+            .step_by(NUMBER_STEP)
+            .map(Marker::Number)
+            .filter(|number| !board.get_markers().contains(number))
+            .collect()
+    }
+
+    fn chooses_markers(&self) -> bool {
+        true
+    }
+
+    fn is_line_won_by(&self, line: &[String], player: &Marker, board: &Board) -> bool {
+        let values: Vec<i32> = line.iter().filter_map(|space| space.parse().ok()).collect();
+        values.len() == line.len() && values.iter().sum::<i32>() == find_target_sum(board)
+            && player == &marker::opponent(&self.find_current_player(board))
+    }
+
+    fn find_heuristic_score(&self, _board: &Board) -> i32 {
+        NO_ADVANTAGE
+    }
+
+    fn find_search_depth(&self, _board: &Board) -> Option<i32> {
+        Some(NUMERICAL_SEARCH_DEPTH)
+    }
+
+    fn format_move(&self, next_move: &Move) -> String {
+        format!(
+            "{}{}{}",
+            next_move.space + OFFSET,
+            VALUE_PREFIX,
+            marker::inspect(&next_move.marker)
+        )
+    }
+
+    fn format_value(&self, value: &str) -> String {
+        format!("{}{}", VALUE_PREFIX, value)
+    }
+}
+
+pub fn find_rules(board: &Board) -> &'static dyn Rules {
+    variant::find_rules(*board.get_variant())
+}
+
+pub fn format_placed_move(next_move: &Move) -> String {
+    format!(
+        "{}{}",
+        next_move.space + OFFSET,
+        marker::inspect(&next_move.marker).to_lowercase()
+    )
+}
+
+pub fn find_target_sum(board: &Board) -> i32 {
+    board.get_win_length() * (board.get_number_of_spaces() + 1) / 2
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+    #[cfg(test)]
+    use board::build_board;
+    #[cfg(test)]
+    use variant::Variant;
+    #[cfg(test)]
+    use board::tests::set_up_board;
+
+    #[test]
+    fn finds_the_rules_of_each_variant() {
+        let board = set_up_board(3, vec![0, 3, 1, 4, 2]);
+        assert_eq!(Marker::X, find_rules(&board).find_winner(&board));
+        let misere = board.clone().with_variant(Variant::Misere);
+        assert_eq!(Marker::O, find_rules(&misere).find_winner(&misere));
+        let wild = board.with_variant(Variant::Wild);
+        assert_eq!(2, find_rules(&wild).find_playable_markers(&wild).len());
+    }
+
+    #[test]
+    fn standard_moves_place_the_current_players_marker_in_a_free_space() {
+        let board = set_up_board(3, vec![0, 4]);
+        let moves = StandardRules.find_legal_moves(&board);
+        assert_eq!(7, moves.len());
+        assert!(moves.iter().all(|next_move| next_move.marker == Marker::X));
+        assert!(!StandardRules.is_move_legal(&board, &Move { space: 4, marker: Marker::X }));
+        assert!(!StandardRules.is_move_legal(&board, &Move { space: 1, marker: Marker::O }));
+    }
+
+    #[test]
+    fn a_drawn_game_is_over_without_a_winner() {
+        let board = set_up_board(3, vec![0, 4, 8, 2, 6, 7, 1, 3, 5]);
+        assert!(StandardRules.is_game_over(&board));
+        assert!(StandardRules.is_game_tied(&board));
+        assert_eq!(Marker::NA, StandardRules.find_winner(&board));
+    }
+
+    #[test]
+    fn limits_the_search_of_larger_games() {
+        assert_eq!(None, StandardRules.find_search_depth(&build_board(3)));
+        assert_eq!(
+            Some(CUBE_SEARCH_DEPTH),
+            StandardRules.find_search_depth(&build_board(4).with_dimensions(3))
+        );
+    }
+
+    #[test]
+    fn numerical_players_place_unused_odd_or_even_numbers() {
+        let board = build_board(3).with_variant(Variant::Numerical);
+        assert_eq!(
+            vec!["1", "3", "5", "7", "9"],
+            NumericalRules
+                .find_playable_markers(&board)
+                .iter()
+                .map(marker::inspect)
+                .collect::<Vec<String>>()
+        );
+        let board = board.play(&NumericalRules, Move { space: 0, marker: Marker::Number(5) });
+        assert_eq!(
+            vec![Marker::Number(2), Marker::Number(4), Marker::Number(6), Marker::Number(8)],
+            NumericalRules.find_playable_markers(&board)
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use board;
+use lines;
+use marker;
+use board_formatter;
+use rules::{Rules, StandardRules};
+use board::{Board, Move};
+use outcome::Outcome;
+use marker::Marker;
+use symmetry::Symmetry;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+
+const SIZE: i32 = 3;
+const FLAT: i32 = 2;
+const SINGLE_BOARD: i32 = 1;
+const SPACES: usize = 9;
+const EMPTY: u32 = 0;
+const MOVER: u32 = 1;
+const OPPONENT: u32 = 2;
+const CELL_STATES: u32 = 3;
+const NEXT_PLY: i32 = 1;
+const WINNING_PLY: i32 = 1;
+const WIN: i32 = 2;
+const DRAW: i32 = 1;
+const LOSS: i32 = 0;
+pub const DRAWN: i32 = 0;
+const TABLE: &str = include_str!("solved_table.txt");
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Solution {
+    pub best_move: Move,
+    pub value: i32,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Entry {
+    value: i32,
+    best_spaces: u32,
+}
+
+pub fn find_solution(board: &Board) -> Option<Solution> {
+    if !is_solved(board) || StandardRules.is_game_over(board) {
+        return None;
+    }
+    let (code, symmetry) = find_canonical_code(board);
+    let entry = load_table().get(&code)?;
+    let lines = lines::find_line_spaces(SIZE, FLAT, SIZE);
+    let marker = StandardRules.find_current_player(board);
+    let best_move = (0..SPACES as i32)
+        .filter(|space| entry.best_spaces & 1 << space != 0)
+        .map(|space| board.restore_move(symmetry, Move { space, marker }))
+        .min_by_key(|next_move| {
+            (
+                Reverse(count_lines_through(&lines, next_move.space)),
+                next_move.space,
+            )
+        })?;
+    Some(Solution {
+        best_move,
+        value: entry.value,
+    })
+}
+
+pub fn generate_table() -> String {
+    let mut table = BTreeMap::new();
+    solve(&board::build_board(SIZE), &mut table);
+    table
+        .iter()
+        .map(|(code, entry)| format!("{} {} {}", code, entry.value, entry.best_spaces))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn count_lines_through(lines: &[Vec<i32>], space: i32) -> usize {
+    lines.iter().filter(|line| line.contains(&space)).count()
+}
+
+fn is_solved(board: &Board) -> bool {
+    *board.get_size() == SIZE
+        && *board.get_dimensions() == FLAT
+        && *board.get_win_length() == SIZE
+        && *board.get_boards() == SINGLE_BOARD
+}
+
+fn load_table() -> &'static HashMap<u32, Entry> {
+    static SOLVED: OnceLock<HashMap<u32, Entry>> = OnceLock::new();
+    SOLVED.get_or_init(|| TABLE.lines().filter_map(parse_entry).collect())
+}
+
+fn parse_entry(line: &str) -> Option<(u32, Entry)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    match fields[..] {
+        [code, value, best_spaces] => Some((
+            code.parse().ok()?,
+            Entry {
+                value: value.parse().ok()?,
+                best_spaces: best_spaces.parse().ok()?,
+            },
+        )),
+        _ => None,
+    }
+}
+
+fn solve(board: &Board, table: &mut BTreeMap<u32, Entry>) -> i32 {
+    let (code, symmetry) = find_canonical_code(board);
+    if let Some(entry) = table.get(&code) {
+        return entry.value;
+    }
+    let values: Vec<(i32, i32)> = StandardRules
+        .find_legal_moves(board)
+        .into_iter()
+        .map(|next_move| {
+            let next_board = board.clone().play(&StandardRules, next_move);
+            let value = match StandardRules.find_outcome(&next_board) {
+                Outcome::InProgress => extend(solve(&next_board, table)),
+                Outcome::Draw => DRAWN,
+                _ => WINNING_PLY,
+            };
+            (next_move.space, value)
+        })
+        .collect();
+    let best_value = values
+        .iter()
+        .map(|&(_, value)| value)
+        .max_by_key(|value| rank(*value))
+        .expect("An unfinished game has a move");
+    let marker = StandardRules.find_current_player(board);
+    let best_spaces = (0..SPACES as i32)
+        .filter(|space| {
+            let next_move = board.restore_move(symmetry, Move { space: *space, marker });
+            values.contains(&(next_move.space, best_value))
+        })
+        .fold(0, |spaces, space| spaces | 1 << space);
+    table.insert(
+        code,
+        Entry {
+            value: best_value,
+            best_spaces,
+        },
+    );
+    best_value
+}
+
+fn extend(opponent_value: i32) -> i32 {
+    if opponent_value > DRAWN {
+        -(opponent_value + NEXT_PLY)
+    } else if opponent_value < DRAWN {
+        -opponent_value + NEXT_PLY
+    } else {
+        DRAWN
+    }
+}
+
+fn rank(value: i32) -> (i32, i32) {
+    if value > DRAWN {
+        (WIN, -value)
+    } else if value < DRAWN {
+        (LOSS, -value)
+    } else {
+        (DRAW, DRAWN)
+    }
+}
+
+fn read_cells(board: &Board) -> Vec<u32> {
+    let mover = marker::inspect(&StandardRules.find_current_player(board));
+    board_formatter::expand_board(board)
+        .iter()
+        .map(|cell| {
+            if *cell == mover {
+                MOVER
+            } else if cell.trim().is_empty() {
+                EMPTY
+            } else {
+                OPPONENT
+            }
+        })
+        .collect()
+}
+
+fn find_canonical_code(board: &Board) -> (u32, Symmetry) {
+    let (symmetry, canonical) = orient(board).find_canonical_form();
+    let code = read_cells(&canonical)
+        .iter()
+        .rev()
+        .fold(0, |code, cell| code * CELL_STATES + cell);
+    (code, symmetry)
+}
+
+fn orient(board: &Board) -> Board {
+    if *board.get_first_player() == Marker::X {
+        return board.clone();
+    }
+    let moves = board
+        .get_spaces()
+        .iter()
+        .zip(board.get_markers())
+        .map(|(space, marker)| Move {
+            space: *space,
+            marker: marker::opponent(marker),
+        })
+        .collect();
+    board.clone().with_first_player(Marker::X).with_moves(moves)
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+    #[cfg(test)]
+    use board::tests::set_up_board;
+    #[cfg(test)]
+    use computer;
+
+    #[test]
+    fn the_stored_table_matches_a_fresh_solve() {
+        assert_eq!(TABLE.trim_end(), generate_table());
+    }
+
+    #[test]
+    fn stores_each_position_once_up_to_symmetry() {
+        assert_eq!(627, load_table().len());
+    }
+
+    #[test]
+    fn the_empty_board_is_a_draw() {
+        let solution = find_solution(&board::build_board(SIZE)).unwrap();
+        assert_eq!(DRAWN, solution.value);
+        assert_eq!(Move { space: 4, marker: Marker::X }, solution.best_move);
+    }
+
+    #[test]
+    fn prefers_the_drawing_space_on_the_most_lines() {
+        assert_eq!(0, find_solution(&set_up_board(3, vec![4])).unwrap().best_move.space);
+        assert_eq!(4, find_solution(&set_up_board(3, vec![8])).unwrap().best_move.space);
+    }
+
+    #[test]
+    fn finds_the_winning_space_in_every_orientation() {
+        assert_eq!(2, find_solution(&set_up_board(3, vec![0, 4, 1, 6])).unwrap().best_move.space);
+        assert_eq!(8, find_solution(&set_up_board(3, vec![2, 4, 5, 6])).unwrap().best_move.space);
+        assert_eq!(6, find_solution(&set_up_board(3, vec![8, 4, 7, 2])).unwrap().best_move.space);
+    }
+
+    #[test]
+    fn solves_games_where_o_moves_first() {
+        let board = board::build_board(SIZE)
+            .with_first_player(Marker::O)
+            .place_marker(&StandardRules, 0)
+            .place_marker(&StandardRules, 4)
+            .place_marker(&StandardRules, 1)
+            .place_marker(&StandardRules, 6);
+        let solution = find_solution(&board).unwrap();
+        assert_eq!(Move { space: 2, marker: Marker::O }, solution.best_move);
+        assert_eq!(WINNING_PLY, solution.value);
+    }
+
+    #[test]
+    fn agrees_with_a_full_search() {
+        for board in &[
+            set_up_board(3, vec![0, 4, 8]),
+            set_up_board(3, vec![0, 1, 4]),
+            set_up_board(3, vec![4, 0, 8, 2]),
+            set_up_board(3, vec![1, 4, 7]),
+        ] {
+            let scores = computer::score_moves(&StandardRules, board, computer::SINGLE_THREAD);
+            let best_move = find_solution(board).unwrap().best_move;
+            let best = scores.values().map(|score| score.value).max();
+            assert_eq!(best, scores.get(&best_move).map(|score| score.value));
+        }
+    }
+
+    #[test]
+    fn leaves_other_boards_to_the_search() {
+        assert_eq!(None, find_solution(&board::build_board(4)));
+        assert_eq!(None, find_solution(&board::build_board(3).with_win_length(2)));
+        assert_eq!(None, find_solution(&set_up_board(3, vec![0, 3, 1, 4, 2])));
+    }
+}
@@ -0,0 +1,261 @@
+use lines;
+use marker;
+use tactics;
+use computer;
+use heuristic;
+use board_formatter;
+use board::{Board, Move};
+use computer::{Budget, Evaluation};
+use marker::Marker;
+use rules::Rules;
+
+const LAST_OFFSET: i32 = 1;
+const SINGLE_FORK: usize = 1;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Rule {
+    Win,
+    Block,
+    Fork,
+    BlockFork,
+    Centre,
+    OppositeCorner,
+    EmptyCorner,
+    EmptySide,
+}
+
+pub fn choose_rule<R: Rules + ?Sized>(rules: &R, board: &Board) -> (Rule, Move) {
+    let moves = rules.find_legal_moves(board);
+    if let Some(winning_move) = moves
+        .iter()
+        .find(|next_move| tactics::is_winning_move(rules, board, **next_move))
+    {
+        return (Rule::Win, *winning_move);
+    }
+    let player = rules.find_current_player(board);
+    let spaces: Vec<i32> = moves.iter().map(|next_move| next_move.space).collect();
+    let (rule, space) = find_line_space(rules, board, &player)
+        .or_else(|| find_positional_space(board, &player, &spaces))
+        .unwrap_or((Rule::EmptySide, spaces[0]));
+    let marker = rules.find_default_marker(board);
+    let chosen = moves
+        .iter()
+        .find(|next_move| next_move.space == space && next_move.marker == marker)
+        .or_else(|| moves.iter().find(|next_move| next_move.space == space))
+        .expect("The space chosen has a legal move");
+    (rule, *chosen)
+}
+
+pub fn accepts_draw<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    player: &Marker,
+    budget: Option<Budget>,
+) -> bool {
+    let scores = computer::score_candidates(rules, board, budget, computer::SINGLE_THREAD);
+    if scores.is_empty() {
+        return true;
+    }
+    let is_to_move = rules.find_current_player(board) == *player;
+    match computer::evaluate(&computer::find_highest_score(&scores).1) {
+        Evaluation::Win(_) => !is_to_move,
+        Evaluation::Loss(_) => is_to_move,
+        _ => true,
+    }
+}
+
+fn find_line_space<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    player: &Marker,
+) -> Option<(Rule, i32)> {
+    if !rules.has_lines_to_complete(board) {
+        return None;
+    }
+    let opponent = marker::opponent(player);
+    tactics::find_completing_spaces(board, &opponent)
+        .first()
+        .map(|space| (Rule::Block, *space))
+        .or_else(|| {
+            tactics::find_forking_spaces(board, player)
+                .first()
+                .map(|space| (Rule::Fork, *space))
+        })
+        .or_else(|| find_fork_block(board, player).map(|space| (Rule::BlockFork, space)))
+}
+
+fn find_fork_block(board: &Board, player: &Marker) -> Option<i32> {
+    let forks = tactics::find_forking_spaces(board, &marker::opponent(player));
+    if forks.len() <= SINGLE_FORK {
+        return forks.first().cloned();
+    }
+    (0..board.get_number_of_spaces())
+        .filter(|space| board.is_space_available(space))
+        .find(|space| forces_a_safe_block(board, *space, player))
+        .or_else(|| forks.first().cloned())
+}
+
+fn forces_a_safe_block(board: &Board, space: i32, player: &Marker) -> bool {
+    let opponent = marker::opponent(player);
+    let threatened = tactics::place(board, space, player);
+    let threats = tactics::find_completing_spaces(&threatened, player);
+    !threats.is_empty()
+        && threats.iter().all(|threat| {
+            !tactics::has_fork(&tactics::place(&threatened, *threat, &opponent), &opponent)
+        })
+}
+
+fn find_positional_space(board: &Board, player: &Marker, spaces: &[i32]) -> Option<(Rule, i32)> {
+    let corners = find_corners(board);
+    let markers = board_formatter::expand_board(board);
+    let opponent = marker::inspect(&marker::opponent(player));
+    heuristic::find_centre_spaces(board)
+        .into_iter()
+        .find(|space| spaces.contains(space))
+        .map(|space| (Rule::Centre, space))
+        .or_else(|| {
+            corners
+                .iter()
+                .find(|corner| {
+                    spaces.contains(corner)
+                        && markers[find_opposite_corner(board, **corner) as usize] == opponent
+                })
+                .map(|corner| (Rule::OppositeCorner, *corner))
+        })
+        .or_else(|| {
+            corners
+                .iter()
+                .find(|corner| spaces.contains(corner))
+                .map(|corner| (Rule::EmptyCorner, *corner))
+        })
+}
+
+fn find_corners(board: &Board) -> Vec<i32> {
+    let size = *board.get_size();
+    let dimensions = *board.get_dimensions();
+    (0..size.pow(dimensions as u32))
+        .filter(|space| {
+            lines::find_coordinates(*space, size, dimensions)
+                .iter()
+                .all(|coordinate| *coordinate == 0 || *coordinate == size - LAST_OFFSET)
+        })
+        .collect()
+}
+
+fn find_opposite_corner(board: &Board, corner: i32) -> i32 {
+    board.get_size().pow(*board.get_dimensions() as u32) - LAST_OFFSET - corner
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+    #[cfg(test)]
+    use board::build_board;
+    #[cfg(test)]
+    use board::tests::set_up_board;
+    #[cfg(test)]
+    use computer;
+    #[cfg(test)]
+    use computer::tests::find_move;
+    #[cfg(test)]
+    use rules::{find_rules, StandardRules};
+    #[cfg(test)]
+    use variant::Variant;
+
+    #[cfg(test)]
+    fn find_rule(spaces: Vec<i32>) -> (Rule, i32) {
+        let (rule, next_move) = choose_rule(&StandardRules, &set_up_board(3, spaces));
+        (rule, next_move.space)
+    }
+
+    #[cfg(test)]
+    fn never_loses(board: Board, player: &Marker) -> bool {
+        if StandardRules.is_game_over(&board) {
+            return StandardRules.find_winner(&board) != marker::opponent(player);
+        }
+        if StandardRules.find_current_player(&board) == *player {
+            let (_, next_move) = choose_rule(&StandardRules, &board);
+            return never_loses(board.play(&StandardRules, next_move), player);
+        }
+        StandardRules
+            .find_legal_moves(&board)
+            .into_iter()
+            .all(|next_move| never_loses(board.clone().play(&StandardRules, next_move), player))
+    }
+
+    #[test]
+    fn takes_the_centre_of_an_empty_board() {
+        assert_eq!((Rule::Centre, 4), find_rule(vec![]));
+    }
+
+    #[test]
+    fn wins_before_blocking() {
+        assert_eq!((Rule::Win, 2), find_rule(vec![0, 3, 1, 4]));
+    }
+
+    #[test]
+    fn blocks_the_opponents_line() {
+        assert_eq!((Rule::Block, 2), find_rule(vec![0, 4, 8, 1, 7, 6]));
+    }
+
+    #[test]
+    fn makes_two_threats_at_once() {
+        assert_eq!((Rule::Fork, 6), find_rule(vec![0, 1, 2, 8]));
+    }
+
+    #[test]
+    fn blocks_a_fork_by_forcing_a_reply_on_a_side() {
+        assert_eq!((Rule::BlockFork, 1), find_rule(vec![0, 4, 8]));
+    }
+
+    #[test]
+    fn answers_a_corner_with_the_opposite_corner() {
+        assert_eq!((Rule::OppositeCorner, 8), find_rule(vec![4, 0]));
+    }
+
+    #[test]
+    fn takes_an_empty_corner() {
+        assert_eq!((Rule::EmptyCorner, 0), find_rule(vec![4]));
+    }
+
+    #[test]
+    fn never_loses_whatever_the_opponent_plays() {
+        assert!(never_loses(build_board(3), &Marker::X));
+        assert!(never_loses(build_board(3), &Marker::O));
+    }
+
+    #[test]
+    fn draws_against_the_search() {
+        let mut board = build_board(3);
+        while !StandardRules.is_game_over(&board) {
+            let next_move = match StandardRules.find_current_player(&board) {
+                Marker::X => choose_rule(&StandardRules, &board).1,
+                _ => find_move(&StandardRules, &board, computer::SINGLE_THREAD),
+            };
+            board = board.play(&StandardRules, next_move);
+        }
+        assert_eq!(Marker::NA, StandardRules.find_winner(&board));
+    }
+
+    #[test]
+    fn declines_a_draw_while_holding_a_fork() {
+        let board = set_up_board(3, vec![4, 0]);
+        assert!(accepts_draw(&StandardRules, &board, &Marker::O, None));
+        let board = set_up_board(3, vec![0, 1, 2, 8, 6]);
+        assert!(!accepts_draw(&StandardRules, &board, &Marker::X, None));
+    }
+
+    #[test]
+    fn declines_a_draw_when_a_threat_leads_to_a_forced_win() {
+        let board = set_up_board(3, vec![0, 1, 4]);
+        assert!(!tactics::has_fork(&board, &Marker::X));
+        assert!(!accepts_draw(&StandardRules, &board, &Marker::X, None));
+    }
+
+    #[test]
+    fn plays_by_position_where_completing_a_line_is_not_a_win() {
+        let board = set_up_board(3, vec![4, 0, 8, 2, 6]).with_variant(Variant::Misere);
+        let (rule, next_move) = choose_rule(find_rules(&board), &board);
+        assert_eq!((Rule::EmptySide, 1), (rule, next_move.space));
+    }
+}
@@ -0,0 +1,91 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Symmetry {
+    Identity,
+    RotateRight,
+    RotateHalf,
+    RotateLeft,
+    FlipHorizontal,
+    FlipVertical,
+    FlipDiagonal,
+    FlipAntiDiagonal,
+}
+
+pub const SYMMETRIES: [Symmetry; 8] = [
+    Symmetry::Identity,
+    Symmetry::RotateRight,
+    Symmetry::RotateHalf,
+    Symmetry::RotateLeft,
+    Symmetry::FlipHorizontal,
+    Symmetry::FlipVertical,
+    Symmetry::FlipDiagonal,
+    Symmetry::FlipAntiDiagonal,
+];
+
+const LAST_OFFSET: i32 = 1;
+
+pub fn transform_space(symmetry: Symmetry, space: i32, size: i32) -> i32 {
+    let row = space / size;
+    let column = space % size;
+    let last = size - LAST_OFFSET;
+    let (row, column) = match symmetry {
+        Symmetry::Identity => (row, column),
+        Symmetry::RotateRight => (column, last - row),
+        Symmetry::RotateHalf => (last - row, last - column),
+        Symmetry::RotateLeft => (last - column, row),
+        Symmetry::FlipHorizontal => (row, last - column),
+        Symmetry::FlipVertical => (last - row, column),
+        Symmetry::FlipDiagonal => (column, row),
+        Symmetry::FlipAntiDiagonal => (last - column, last - row),
+    };
+    row * size + column
+}
+
+pub fn restore_space(symmetry: Symmetry, space: i32, size: i32) -> i32 {
+    transform_space(invert(symmetry), space, size)
+}
+
+fn invert(symmetry: Symmetry) -> Symmetry {
+    match symmetry {
+        Symmetry::RotateRight => Symmetry::RotateLeft,
+        Symmetry::RotateLeft => Symmetry::RotateRight,
+        symmetry => symmetry,
+    }
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+
+    #[test]
+    fn rotates_a_corner_around_the_board() {
+        assert_eq!(2, transform_space(Symmetry::RotateRight, 0, 3));
+        assert_eq!(8, transform_space(Symmetry::RotateHalf, 0, 3));
+        assert_eq!(6, transform_space(Symmetry::RotateLeft, 0, 3));
+        assert_eq!(3, transform_space(Symmetry::RotateRight, 0, 4));
+    }
+
+    #[test]
+    fn reflects_a_space_across_each_axis() {
+        assert_eq!(2, transform_space(Symmetry::FlipHorizontal, 0, 3));
+        assert_eq!(6, transform_space(Symmetry::FlipVertical, 0, 3));
+        assert_eq!(3, transform_space(Symmetry::FlipDiagonal, 1, 3));
+        assert_eq!(5, transform_space(Symmetry::FlipAntiDiagonal, 1, 3));
+    }
+
+    #[test]
+    fn the_centre_never_moves() {
+        for symmetry in &SYMMETRIES {
+            assert_eq!(4, transform_space(*symmetry, 4, 3));
+        }
+    }
+
+    #[test]
+    fn restoring_a_space_undoes_the_transform() {
+        for symmetry in &SYMMETRIES {
+            for space in 0..16 {
+                let transformed = transform_space(*symmetry, space, 4);
+                assert_eq!(space, restore_space(*symmetry, transformed, 4));
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use lines;
+use marker;
+use outcome;
+use board_formatter;
+use board::{Board, Move};
+use marker::Marker;
+use rules::Rules;
+
+const MISSING: usize = 1;
+const FORKING_THREATS: usize = 2;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Tactic {
+    Win,
+    Block,
+    Fork,
+}
+
+pub fn find_tactics<R: Rules + ?Sized>(rules: &R, board: &Board, next_move: Move) -> Vec<Tactic> {
+    if is_winning_move(rules, board, next_move) {
+        return vec![Tactic::Win];
+    }
+    if !rules.has_lines_to_complete(board) {
+        return Vec::new();
+    }
+    let player = rules.find_current_player(board);
+    let mut tactics = Vec::new();
+    if find_completing_spaces(board, &marker::opponent(&player)).contains(&next_move.space) {
+        tactics.push(Tactic::Block);
+    }
+    if has_fork(&board.clone().play(rules, next_move), &player) {
+        tactics.push(Tactic::Fork);
+    }
+    tactics
+}
+
+pub fn is_winning_move<R: Rules + ?Sized>(rules: &R, board: &Board, next_move: Move) -> bool {
+    let next_board = board.clone().play(rules, next_move);
+    outcome::is_won_by(
+        &rules.find_outcome(&next_board),
+        &rules.find_current_player(board),
+    )
+}
+
+pub fn find_completing_spaces(board: &Board, player: &Marker) -> Vec<i32> {
+    let markers = board_formatter::expand_board(board);
+    let own = marker::inspect(player);
+    let mut spaces: Vec<i32> = lines::find_line_spaces(
+        *board.get_size(),
+        *board.get_dimensions(),
+        *board.get_win_length(),
+    )
+    .iter()
+    .filter_map(|line| {
+        let empty: Vec<&i32> = line
+            .iter()
+            .filter(|space| board.is_space_available(space))
+            .collect();
+        let owned = line
+            .iter()
+            .filter(|space| markers[**space as usize] == own)
+            .count();
+        if empty.len() == MISSING && owned + MISSING == line.len() {
+            Some(*empty[0])
+        } else {
+            None
+        }
+    })
+    .collect();
+    spaces.sort();
+    spaces.dedup();
+    spaces
+}
+
+pub fn has_fork(board: &Board, player: &Marker) -> bool {
+    find_completing_spaces(board, player).len() >= FORKING_THREATS
+}
+
+pub fn find_forking_spaces(board: &Board, player: &Marker) -> Vec<i32> {
+    (0..board.get_number_of_spaces())
+        .filter(|space| board.is_space_available(space))
+        .filter(|space| has_fork(&place(board, *space, player), player))
+        .collect()
+}
+
+pub fn place(board: &Board, space: i32, player: &Marker) -> Board {
+    let moves = board
+        .get_spaces()
+        .iter()
+        .zip(board.get_markers())
+        .map(|(space, marker)| Move {
+            space: *space,
+            marker: *marker,
+        })
+        .chain(Some(Move {
+            space,
+            marker: *player,
+        }))
+        .collect();
+    board.clone().with_moves(moves)
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+    #[cfg(test)]
+    use board::tests::set_up_board;
+    #[cfg(test)]
+    use rules::{MisereRules, StandardRules};
+    #[cfg(test)]
+    use variant::Variant;
+
+    #[test]
+    fn finds_the_spaces_that_complete_a_line() {
+        let board = set_up_board(3, vec![0, 3, 1, 4, 8]);
+        assert_eq!(vec![2], find_completing_spaces(&board, &Marker::X));
+        assert_eq!(vec![5], find_completing_spaces(&board, &Marker::O));
+    }
+
+    #[test]
+    fn a_move_that_completes_a_line_wins() {
+        let board = set_up_board(3, vec![0, 3, 1, 4]);
+        let next_move = Move { space: 2, marker: Marker::X };
+        assert_eq!(vec![Tactic::Win], find_tactics(&StandardRules, &board, next_move));
+    }
+
+    #[test]
+    fn a_move_on_the_opponents_line_blocks_it() {
+        let board = set_up_board(3, vec![0, 4, 8, 2]);
+        let next_move = Move { space: 6, marker: Marker::X };
+        assert_eq!(
+            vec![Tactic::Block, Tactic::Fork],
+            find_tactics(&StandardRules, &board, next_move)
+        );
+    }
+
+    #[test]
+    fn a_move_that_makes_two_threats_forks() {
+        let board = set_up_board(3, vec![0, 4, 8, 1]);
+        let next_move = Move { space: 6, marker: Marker::X };
+        assert_eq!(vec![Tactic::Fork], find_tactics(&StandardRules, &board, next_move));
+        let next_move = Move { space: 7, marker: Marker::X };
+        assert_eq!(vec![Tactic::Block], find_tactics(&StandardRules, &board, next_move));
+        let next_move = Move { space: 3, marker: Marker::X };
+        assert_eq!(Vec::<Tactic>::new(), find_tactics(&StandardRules, &board, next_move));
+    }
+
+    #[test]
+    fn finds_the_spaces_where_either_player_could_fork() {
+        let board = set_up_board(3, vec![0, 4, 8]);
+        assert_eq!(vec![2, 6], find_forking_spaces(&board, &Marker::X));
+        assert!(find_forking_spaces(&board, &Marker::O).is_empty());
+    }
+
+    #[test]
+    fn only_looks_for_threats_where_completing_a_line_wins() {
+        let board = set_up_board(3, vec![0, 4, 8, 2]).with_variant(Variant::Misere);
+        let next_move = Move { space: 6, marker: Marker::X };
+        assert!(find_tactics(&MisereRules, &board, next_move).is_empty());
+    }
+}
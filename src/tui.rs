@@ -0,0 +1,410 @@
+extern crate termion;
+use io;
+use board_formatter;
+use board_printer;
+use board::{Board, Move};
+use action::Action;
+use marker;
+use marker::Marker;
+use rules::Rules;
+use std::io::{stdin, stdout, Write};
+use std::process;
+use self::termion::cursor::{Goto, Hide, Show};
+use self::termion::event::{Event, Key, MouseButton, MouseEvent};
+use self::termion::input::{MouseTerminal, TermRead};
+use self::termion::raw::IntoRawMode;
+use self::termion::style::{Invert, Reset};
+
+const OFFSET: usize = 1;
+const PANEL_GAP: usize = 4;
+const BOARD_TOP: usize = 3;
+const MOVES: &str = "Moves";
+const KEY_HELP: &str =
+    "Arrows/hjkl move | Enter or click place | ? hint | r resign | d offer draw | q quit";
+const WILD_KEY_HELP: &str =
+    "Arrows/hjkl move | x/o marker | Enter or click place | ? hint | r resign | d draw | q quit";
+const NUMERICAL_KEY_HELP: &str =
+    "Arrows/hjkl move | 1-9 number | Enter or click place | ? hint | r resign | d draw | q quit";
+const SPACE_TAKEN: &str = "that space is taken";
+
+pub fn is_available() -> bool {
+    termion::is_tty(&stdin()) && termion::is_tty(&stdout())
+}
+
+pub fn select_action<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    notice: &str,
+    details: &str,
+) -> Action {
+    let mut screen = MouseTerminal::from(
+        stdout()
+            .into_raw_mode()
+            .expect("Unable to enter raw mode"),
+    );
+    let mut cursor = find_starting_space(rules, board);
+    let mut marker = find_starting_marker(rules, board);
+    let mut message = notice;
+    let stdin = stdin();
+    let mut events = stdin.lock().events();
+    loop {
+        let lines = compose_screen(rules, board, cursor, &marker, message, details);
+        draw(&mut screen, &lines);
+        let placing = match events.next() {
+            Some(Ok(Event::Key(Key::Char('\n')))) => true,
+            Some(Ok(Event::Key(Key::Char('?')))) => return finish(&mut screen, Action::Hint),
+            Some(Ok(Event::Key(Key::Char('r')))) => return finish(&mut screen, Action::Resign),
+            Some(Ok(Event::Key(Key::Char('d')))) => {
+                return finish(&mut screen, Action::OfferDraw)
+            }
+            Some(Ok(Event::Key(Key::Char('q'))))
+            | Some(Ok(Event::Key(Key::Ctrl('c'))))
+            | Some(Err(_))
+            | None => quit(screen),
+            Some(Ok(Event::Key(Key::Char(symbol))))
+                if choose_marker(rules, board, symbol).is_some() =>
+            {
+                marker = choose_marker(rules, board, symbol).unwrap_or(marker);
+                false
+            }
+            Some(Ok(Event::Key(key))) => {
+                cursor = move_cursor(cursor, &key, *board.get_size(), count_layers(board));
+                false
+            }
+            Some(Ok(Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y)))) => {
+                match find_clicked_space(rules, board, x, y) {
+                    Some(space) => {
+                        cursor = space;
+                        true
+                    }
+                    None => false,
+                }
+            }
+            Some(Ok(_)) => false,
+        };
+        message = "";
+        if placing {
+            if rules.find_playable_spaces(board).contains(&cursor) {
+                break;
+            }
+            message = SPACE_TAKEN;
+        }
+    }
+    if rules.chooses_markers() {
+        finish(
+            &mut screen,
+            Action::Place(Move {
+                space: cursor,
+                marker,
+            }),
+        )
+    } else {
+        finish(&mut screen, Action::Move(cursor))
+    }
+}
+
+pub fn find_starting_marker<R: Rules + ?Sized>(rules: &R, board: &Board) -> Marker {
+    let default_marker = rules.find_default_marker(board);
+    let markers = rules.find_playable_markers(board);
+    if markers.contains(&default_marker) {
+        default_marker
+    } else {
+        *markers.first().unwrap_or(&Marker::NA)
+    }
+}
+
+pub fn choose_marker<R: Rules + ?Sized>(rules: &R, board: &Board, symbol: char) -> Option<Marker> {
+    if !rules.chooses_markers() {
+        return None;
+    }
+    let symbol = symbol.to_ascii_uppercase().to_string();
+    rules
+        .find_playable_markers(board)
+        .into_iter()
+        .find(|marker| marker::inspect(marker) == symbol)
+}
+
+fn finish<W: Write>(screen: &mut W, action: Action) -> Action {
+    write!(screen, "{}", Show).expect("Unable to write");
+    action
+}
+
+fn draw<W: Write>(screen: &mut W, lines: &[String]) {
+    write!(screen, "{}{}", Hide, termion::clear::All).expect("Unable to write");
+    for (row, line) in lines.iter().enumerate() {
+        write!(screen, "{}{}", Goto(1, (row + OFFSET) as u16), line).expect("Unable to write");
+    }
+    screen.flush().expect("Unable to write");
+}
+
+fn quit<W: Write>(mut screen: W) -> ! {
+    write!(screen, "{}{}{}", termion::clear::All, Goto(1, 1), Show).expect("Unable to write");
+    screen.flush().expect("Unable to write");
+    drop(screen);
+    process::exit(0)
+}
+
+pub fn compose_screen<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    cursor: i32,
+    marker: &Marker,
+    message: &str,
+    details: &str,
+) -> Vec<String> {
+    let mut screen: Vec<String> = vec![
+        io::TITLE.to_string(),
+        format_status(rules, board, marker, message),
+        "".to_string(),
+    ];
+    let board_lines = pad_board_lines(rules, board);
+    let width = board_lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let mut history = vec![MOVES.to_string()];
+    history.append(&mut format_history(board));
+    let rows = board_lines.len().max(history.len());
+    for row in 0..rows {
+        let left = match board_lines.get(row) {
+            Some(line) => apply_highlight(rules, line, board, cursor, row),
+            None => " ".repeat(width),
+        };
+        let right = history.get(row).map(|line| line.as_str()).unwrap_or("");
+        screen.push(format!("{}{}{}", left, " ".repeat(PANEL_GAP), right));
+    }
+    screen.push("".to_string());
+    for line in details.lines() {
+        screen.push(line.to_string());
+    }
+    screen.push(find_key_help(rules, board).to_string());
+    screen
+}
+
+fn find_key_help<R: Rules + ?Sized>(rules: &R, board: &Board) -> &'static str {
+    if !rules.chooses_markers() {
+        return KEY_HELP;
+    }
+    match find_starting_marker(rules, board) {
+        Marker::Number(_) => NUMERICAL_KEY_HELP,
+        _ => WILD_KEY_HELP,
+    }
+}
+
+fn format_status<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    marker: &Marker,
+    message: &str,
+) -> String {
+    let mut status = io::select_space(&rules.find_current_player(board));
+    if rules.chooses_markers() {
+        status += &format!(" to place {}", marker::inspect(marker));
+    }
+    if !message.is_empty() {
+        status += &format!(" - {}", message);
+    }
+    status
+}
+
+fn pad_board_lines<R: Rules + ?Sized>(rules: &R, board: &Board) -> Vec<String> {
+    let formatted_board = rules.format_board(board);
+    let width = formatted_board.lines().map(|line| line.len()).max().unwrap_or(0);
+    formatted_board
+        .lines()
+        .map(|line| format!("{:width$}", line, width = width))
+        .collect()
+}
+
+fn apply_highlight<R: Rules + ?Sized>(
+    rules: &R,
+    line: &str,
+    board: &Board,
+    cursor: i32,
+    row: usize,
+) -> String {
+    match board_printer::locate_spaces(rules, board).get(cursor as usize) {
+        Some(span) if span.line == row => format!(
+            "{}{}{}{}{}",
+            &line[..span.start],
+            Invert,
+            &line[span.start..span.end],
+            Reset,
+            &line[span.end..]
+        ),
+        _ => line.to_string(),
+    }
+}
+
+pub fn find_clicked_space<R: Rules + ?Sized>(
+    rules: &R,
+    board: &Board,
+    x: u16,
+    y: u16,
+) -> Option<i32> {
+    let column = (x as usize).checked_sub(OFFSET)?;
+    let line = (y as usize).checked_sub(OFFSET + BOARD_TOP)?;
+    board_printer::find_space_at(rules, board, column, line)
+}
+
+pub fn format_history(board: &Board) -> Vec<String> {
+    let markers = board_formatter::expand_board(board);
+    board
+        .get_spaces()
+        .iter()
+        .enumerate()
+        .map(|(turn, space)| {
+            format!(
+                "{}. {} {}",
+                turn + OFFSET,
+                markers[*space as usize],
+                space + OFFSET as i32
+            )
+        })
+        .collect()
+}
+
+pub fn find_starting_space<R: Rules + ?Sized>(rules: &R, board: &Board) -> i32 {
+    match rules.find_playable_spaces(board).first() {
+        Some(space) => *space,
+        None => 0,
+    }
+}
+
+fn count_layers(board: &Board) -> i32 {
+    board.get_number_of_spaces() / (board.get_size() * board.get_size())
+}
+
+pub fn move_cursor(cursor: i32, key: &Key, size: i32, layers: i32) -> i32 {
+    let area = size * size;
+    let row = cursor % area / size;
+    let column = cursor / area * size + cursor % size;
+    let (row, column) = match *key {
+        Key::Left | Key::Char('h') if column > 0 => (row, column - 1),
+        Key::Right | Key::Char('l') if column < size * layers - 1 => (row, column + 1),
+        Key::Up | Key::Char('k') if row > 0 => (row - 1, column),
+        Key::Down | Key::Char('j') if row < size - 1 => (row + 1, column),
+        _ => (row, column),
+    };
+    column / size * area + row * size + column % size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use board::tests::set_up_board;
+    use board::build_board;
+    use rules::{NumericalRules, StandardRules, WildRules};
+    use variant::Variant;
+
+    #[test]
+    fn moves_the_cursor_with_the_arrow_keys() {
+        assert_eq!(3, move_cursor(4, &Key::Left, 3, 1));
+        assert_eq!(5, move_cursor(4, &Key::Right, 3, 1));
+        assert_eq!(1, move_cursor(4, &Key::Up, 3, 1));
+        assert_eq!(7, move_cursor(4, &Key::Down, 3, 1));
+    }
+
+    #[test]
+    fn moves_the_cursor_with_hjkl() {
+        assert_eq!(3, move_cursor(4, &Key::Char('h'), 3, 1));
+        assert_eq!(5, move_cursor(4, &Key::Char('l'), 3, 1));
+        assert_eq!(1, move_cursor(4, &Key::Char('k'), 3, 1));
+        assert_eq!(7, move_cursor(4, &Key::Char('j'), 3, 1));
+    }
+
+    #[test]
+    fn keeps_the_cursor_on_the_board() {
+        assert_eq!(3, move_cursor(3, &Key::Left, 3, 1));
+        assert_eq!(5, move_cursor(5, &Key::Right, 3, 1));
+        assert_eq!(2, move_cursor(2, &Key::Up, 3, 1));
+        assert_eq!(15, move_cursor(15, &Key::Down, 4, 1));
+    }
+
+    #[test]
+    fn moves_the_cursor_across_the_layers_of_a_cube() {
+        assert_eq!(16, move_cursor(3, &Key::Right, 4, 4));
+        assert_eq!(3, move_cursor(16, &Key::Left, 4, 4));
+        assert_eq!(63, move_cursor(63, &Key::Right, 4, 4));
+        assert_eq!(25, move_cursor(21, &Key::Down, 4, 4));
+    }
+
+    #[test]
+    fn starts_the_cursor_on_the_first_available_space() {
+        let board: Board = set_up_board(3, vec![0, 1]);
+        assert_eq!(2, find_starting_space(&StandardRules, &board));
+    }
+
+    #[test]
+    fn finds_the_space_that_was_clicked() {
+        let board: Board = set_up_board(3, vec![]);
+        assert_eq!(Some(0), find_clicked_space(&StandardRules, &board, 1, 4));
+        assert_eq!(Some(4), find_clicked_space(&StandardRules, &board, 8, 6));
+        assert_eq!(None, find_clicked_space(&StandardRules, &board, 8, 5));
+        assert_eq!(None, find_clicked_space(&StandardRules, &board, 1, 1));
+        assert_eq!(None, find_clicked_space(&StandardRules, &board, 30, 4));
+    }
+
+    #[test]
+    fn lists_the_moves_played() {
+        let board: Board = set_up_board(3, vec![4, 0, 8]);
+        let history: Vec<String> = vec![
+            "1. X 5".to_string(),
+            "2. O 1".to_string(),
+            "3. X 9".to_string(),
+        ];
+        assert_eq!(history, format_history(&board));
+    }
+
+    #[test]
+    fn composes_the_status_bar_board_history_and_help() {
+        let board: Board = set_up_board(3, vec![4]);
+        let screen = compose_screen(&StandardRules, &board, 0, &Marker::O, SPACE_TAKEN, "");
+        assert_eq!("Tic Tac Toe", screen[0]);
+        assert_eq!("O, select a space - that space is taken", screen[1]);
+        assert_eq!(
+            format!("{} 1  {}| 2  | 3      Moves", Invert, Reset),
+            screen[3]
+        );
+        assert_eq!("--------------    1. X 5", screen[4]);
+        assert_eq!(KEY_HELP, screen[screen.len() - 1]);
+    }
+
+    #[test]
+    fn chooses_an_unused_number_of_the_current_player_in_numerical() {
+        let next_move = Move {
+            space: 0,
+            marker: Marker::Number(1),
+        };
+        let board: Board = build_board(3)
+            .with_variant(Variant::Numerical)
+            .play(&NumericalRules, next_move);
+        assert_eq!(Marker::Number(2), find_starting_marker(&NumericalRules, &board));
+        assert_eq!(Some(Marker::Number(8)), choose_marker(&NumericalRules, &board, '8'));
+        assert_eq!(None, choose_marker(&NumericalRules, &board, '3'));
+        assert_eq!(None, choose_marker(&NumericalRules, &board, 'x'));
+    }
+
+    #[test]
+    fn chooses_x_or_o_only_in_wild() {
+        let board: Board = build_board(3).with_variant(Variant::Wild);
+        assert_eq!(Some(Marker::O), choose_marker(&WildRules, &board, 'o'));
+        assert_eq!(None, choose_marker(&StandardRules, &build_board(3), 'o'));
+    }
+
+    #[test]
+    fn shows_the_chosen_marker_in_wild() {
+        let board: Board = set_up_board(3, vec![4]).with_variant(Variant::Wild);
+        let screen = compose_screen(&WildRules, &board, 0, &Marker::X, "", "");
+        assert_eq!("O, select a space to place X", screen[1]);
+        assert_eq!(WILD_KEY_HELP, screen[screen.len() - 1]);
+    }
+
+    #[test]
+    fn shows_the_clock_and_the_explanation_above_the_help() {
+        let board: Board = set_up_board(3, vec![4]);
+        let details = "X 4:59 | O 5:00\nX played 5\n1: draw";
+        let screen = compose_screen(&StandardRules, &board, 0, &Marker::O, "", details);
+        assert_eq!("X 4:59 | O 5:00", screen[screen.len() - 4]);
+        assert_eq!("X played 5", screen[screen.len() - 3]);
+        assert_eq!("1: draw", screen[screen.len() - 2]);
+        assert_eq!(KEY_HELP, screen[screen.len() - 1]);
+    }
+}
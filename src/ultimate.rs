@@ -0,0 +1,234 @@
+use game;
+use board;
+use lines;
+use board_printer;
+use heuristic;
+use board::{Board, Move};
+use marker::Marker;
+use rules::Rules;
+use variant::Variant;
+
+pub const ULTIMATE_SIZE: i32 = 9;
+pub const SUB_BOARD_SIZE: i32 = 3;
+const SUB_BOARDS: i32 = 9;
+const ULTIMATE_SEARCH_DEPTH: i32 = 2;
+const META_WEIGHT: i32 = 20;
+const NO_TARGET: i32 = 0;
+
+pub fn build_board(first_player: Marker) -> Board {
+    board::build_board(ULTIMATE_SIZE)
+        .with_win_length(SUB_BOARD_SIZE)
+        .with_first_player(first_player)
+        .with_variant(Variant::Ultimate)
+}
+
+pub fn find_sub_board(space: i32) -> i32 {
+    let row = space / ULTIMATE_SIZE;
+    let column = space % ULTIMATE_SIZE;
+    (row / SUB_BOARD_SIZE) * SUB_BOARD_SIZE + column / SUB_BOARD_SIZE
+}
+
+pub fn find_cell(space: i32) -> i32 {
+    let row = space / ULTIMATE_SIZE;
+    let column = space % ULTIMATE_SIZE;
+    (row % SUB_BOARD_SIZE) * SUB_BOARD_SIZE + column % SUB_BOARD_SIZE
+}
+
+pub fn build_sub_boards(board: &Board) -> Vec<Board> {
+    (0..SUB_BOARDS)
+        .map(|sub_board| {
+            let moves = board
+                .get_spaces()
+                .iter()
+                .zip(board.get_markers())
+                .filter(|&(space, _marker)| find_sub_board(*space) == sub_board)
+                .map(|(space, marker)| Move {
+                    space: find_cell(*space),
+                    marker: *marker,
+                })
+                .collect();
+            board::build_board(SUB_BOARD_SIZE).with_moves(moves)
+        })
+        .collect()
+}
+
+pub fn build_meta_board(board: &Board) -> Board {
+    let moves = build_sub_boards(board)
+        .iter()
+        .enumerate()
+        .map(|(sub_board, cells)| Move {
+            space: sub_board as i32,
+            marker: game::find_winner(cells),
+        })
+        .filter(|next_move| next_move.marker != Marker::NA)
+        .collect();
+    board::build_board(SUB_BOARD_SIZE).with_moves(moves)
+}
+
+fn find_target(board: &Board, open_sub_boards: &[bool]) -> Option<i32> {
+    board
+        .get_spaces()
+        .last()
+        .map(|space| find_cell(*space))
+        .filter(|target| open_sub_boards[*target as usize])
+}
+
+pub fn find_playable_spaces(board: &Board) -> Vec<i32> {
+    let open_sub_boards = find_open_sub_boards(board);
+    let target = find_target(board, &open_sub_boards);
+    (0..ULTIMATE_SIZE * ULTIMATE_SIZE)
+        .filter(|space| {
+            let sub_board = find_sub_board(*space);
+            board.is_space_available(space) && open_sub_boards[sub_board as usize]
+                && target.is_none_or(|target| target == sub_board)
+        })
+        .collect()
+}
+
+fn find_open_sub_boards(board: &Board) -> Vec<bool> {
+    build_sub_boards(board)
+        .iter()
+        .map(|sub_board| !game::is_game_over(sub_board))
+        .collect()
+}
+
+pub struct UltimateRules;
+
+impl Rules for UltimateRules {
+    fn set_up_board(&self, board: Board, _boards: i32) -> Board {
+        build_board(*board.get_first_player())
+    }
+
+    fn uses_last_move(&self) -> bool {
+        true
+    }
+
+    fn find_playable_spaces(&self, board: &Board) -> Vec<i32> {
+        find_playable_spaces(board)
+    }
+
+    fn find_lines(&self, board: &Board) -> Vec<Vec<String>> {
+        lines::find_all_lines(&build_meta_board(board))
+    }
+
+    fn find_winning_line(&self, _board: &Board) -> Vec<i32> {
+        Vec::new()
+    }
+
+    fn find_heuristic_score(&self, board: &Board) -> i32 {
+        let player = self.find_current_player(board);
+        let sub_boards = build_sub_boards(board);
+        let open_sub_boards = find_open_sub_boards(board);
+        let score_sub_board =
+            |sub_board: &Board| heuristic::score_lines(&lines::find_all_lines(sub_board), &player);
+        let meta_score = heuristic::score_lines(&self.find_lines(board), &player) * META_WEIGHT;
+        let sub_board_score: i32 = sub_boards
+            .iter()
+            .zip(&open_sub_boards)
+            .filter(|&(_sub_board, open)| *open)
+            .map(|(sub_board, _open)| score_sub_board(sub_board))
+            .sum();
+        let target_score = find_target(board, &open_sub_boards)
+            .map_or(NO_TARGET, |target| score_sub_board(&sub_boards[target as usize]));
+        meta_score + sub_board_score + target_score
+    }
+
+    fn find_search_depth(&self, _board: &Board) -> Option<i32> {
+        Some(ULTIMATE_SEARCH_DEPTH)
+    }
+
+    fn format_board(&self, board: &Board) -> String {
+        board_printer::format_ultimate_board(self, board)
+    }
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+
+    #[cfg(test)]
+    pub fn set_up_ultimate_board(spaces: Vec<i32>) -> Board {
+        let moves = spaces
+            .iter()
+            .enumerate()
+            .map(|(turn, space)| Move {
+                space: *space,
+                marker: if turn % 2 == 0 { Marker::X } else { Marker::O },
+            })
+            .collect();
+        build_board(Marker::X).with_moves(moves)
+    }
+
+    #[test]
+    fn finds_the_sub_board_and_cell_of_a_space() {
+        assert_eq!((0, 0), (find_sub_board(0), find_cell(0)));
+        assert_eq!((1, 0), (find_sub_board(3), find_cell(3)));
+        assert_eq!((4, 4), (find_sub_board(40), find_cell(40)));
+        assert_eq!((8, 8), (find_sub_board(80), find_cell(80)));
+        assert_eq!((6, 5), (find_sub_board(65), find_cell(65)));
+    }
+
+    #[test]
+    fn splits_the_moves_into_sub_boards() {
+        let sub_boards = build_sub_boards(&set_up_ultimate_board(vec![40, 36]));
+        assert_eq!(&vec![4], sub_boards[4].get_spaces());
+        assert_eq!(&vec![3], sub_boards[3].get_spaces());
+        assert_eq!(&vec![Marker::O], sub_boards[3].get_markers());
+        assert!(sub_boards[0].get_spaces().is_empty());
+    }
+
+    #[test]
+    fn limits_the_search_depth() {
+        assert_eq!(
+            Some(ULTIMATE_SEARCH_DEPTH),
+            UltimateRules.find_search_depth(&set_up_ultimate_board(vec![40]))
+        );
+    }
+
+    #[test]
+    fn favours_threats_in_the_sub_board_the_player_is_sent_to() {
+        let sent_to_threat = set_up_ultimate_board(vec![0, 60, 10, 27]);
+        let sent_elsewhere = set_up_ultimate_board(vec![0, 60, 10, 28]);
+        assert!(
+            UltimateRules.find_heuristic_score(&sent_to_threat)
+                > UltimateRules.find_heuristic_score(&sent_elsewhere)
+        );
+    }
+
+    #[test]
+    fn can_play_anywhere_on_the_first_move() {
+        assert_eq!(81, find_playable_spaces(&build_board(Marker::X)).len());
+    }
+
+    #[test]
+    fn sends_the_opponent_to_the_sub_board_of_the_cell_played() {
+        let board = set_up_ultimate_board(vec![40]);
+        assert_eq!(
+            vec![30, 31, 32, 39, 41, 48, 49, 50],
+            find_playable_spaces(&board)
+        );
+    }
+
+    #[test]
+    fn can_play_in_any_open_sub_board_when_sent_to_a_finished_one() {
+        let board = set_up_ultimate_board(vec![0, 40, 9, 50, 18, 33]);
+        let playable_spaces = find_playable_spaces(&board);
+        assert_eq!(69, playable_spaces.len());
+        assert!(!playable_spaces.contains(&1));
+    }
+
+    #[test]
+    fn rejects_a_move_outside_the_target_sub_board() {
+        let board = build_board(Marker::X)
+            .place_marker(&UltimateRules, 40)
+            .place_marker(&UltimateRules, 0);
+        assert_eq!(&vec![40], board.get_spaces());
+    }
+
+    #[test]
+    fn records_won_sub_boards_on_the_meta_board() {
+        let meta_board = build_meta_board(&set_up_ultimate_board(vec![0, 40, 9, 50, 18]));
+        assert_eq!(&vec![0], meta_board.get_spaces());
+        assert_eq!(&vec![Marker::X], meta_board.get_markers());
+    }
+}
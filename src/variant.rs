@@ -0,0 +1,183 @@
+use board::Board;
+use rules::{MisereRules, NumericalRules, Rules, StandardRules, WildRules};
+use ultimate::UltimateRules;
+use notakto::NotaktoRules;
+use order_and_chaos::OrderAndChaosRules;
+
+const FIRST_CHOICE: i32 = 1;
+const OTHER_BOARDS: &[&str] = &["--boards"];
+const BOARD_SHAPE: &[&str] = &["--size", "--dimensions", "--win-length"];
+const FIXED_BOARD: &[&str] = &["--size", "--dimensions", "--win-length", "--boards"];
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Variant {
+    #[default]
+    Standard,
+    Misere,
+    Wild,
+    Ultimate,
+    Numerical,
+    Notakto,
+    OrderAndChaos,
+}
+
+struct Registration {
+    variant: Variant,
+    name: &'static str,
+    title: &'static str,
+    rules: &'static dyn Rules,
+    ignored_options: &'static [&'static str],
+}
+
+const REGISTRY: &[Registration] = &[
+    Registration {
+        variant: Variant::Standard,
+        name: "standard",
+        title: "Standard",
+        rules: &StandardRules,
+        ignored_options: OTHER_BOARDS,
+    },
+    Registration {
+        variant: Variant::Misere,
+        name: "misere",
+        title: "Misere (completing a line loses)",
+        rules: &MisereRules,
+        ignored_options: OTHER_BOARDS,
+    },
+    Registration {
+        variant: Variant::Wild,
+        name: "wild",
+        title: "Wild (place X or O on each turn)",
+        rules: &WildRules,
+        ignored_options: OTHER_BOARDS,
+    },
+    Registration {
+        variant: Variant::Ultimate,
+        name: "ultimate",
+        title: "Ultimate (nine boards, your space picks the next board)",
+        rules: &UltimateRules,
+        ignored_options: FIXED_BOARD,
+    },
+    Registration {
+        variant: Variant::Numerical,
+        name: "numerical",
+        title: "Numerical (odd against even numbers, a line adding up to 15 wins)",
+        rules: &NumericalRules,
+        ignored_options: FIXED_BOARD,
+    },
+    Registration {
+        variant: Variant::Notakto,
+        name: "notakto",
+        title: "Notakto (both place X, whoever completes the last line loses)",
+        rules: &NotaktoRules,
+        ignored_options: BOARD_SHAPE,
+    },
+    Registration {
+        variant: Variant::OrderAndChaos,
+        name: "order-and-chaos",
+        title: "Order and Chaos (6x6, either marker, Order needs five in a row)",
+        rules: &OrderAndChaosRules,
+        ignored_options: FIXED_BOARD,
+    },
+];
+
+fn find_registration(variant: Variant) -> &'static Registration {
+    REGISTRY
+        .iter()
+        .find(|registration| registration.variant == variant)
+        .expect("Every variant is registered")
+}
+
+pub fn parse(name: &str) -> Option<Variant> {
+    REGISTRY
+        .iter()
+        .find(|registration| registration.name == name)
+        .map(|registration| registration.variant)
+}
+
+pub fn choose(choice: i32) -> Variant {
+    REGISTRY
+        .get((choice - FIRST_CHOICE) as usize)
+        .map_or(Variant::Standard, |registration| registration.variant)
+}
+
+pub fn count_choices() -> i32 {
+    REGISTRY.len() as i32
+}
+
+pub fn format_menu() -> String {
+    REGISTRY
+        .iter()
+        .enumerate()
+        .map(|(index, registration)| {
+            format!("{} - {}", index as i32 + FIRST_CHOICE, registration.title)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn find_rules(variant: Variant) -> &'static dyn Rules {
+    find_registration(variant).rules
+}
+
+pub fn find_ignored_options(variant: Variant) -> &'static [&'static str] {
+    find_registration(variant).ignored_options
+}
+
+pub fn build_board(variant: Variant, board: Board, boards: i32) -> Board {
+    find_rules(variant).set_up_board(board.with_variant(variant), boards)
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+    #[cfg(test)]
+    use board;
+
+    #[test]
+    fn parses_each_variant() {
+        assert_eq!(Some(Variant::Standard), parse("standard"));
+        assert_eq!(Some(Variant::Misere), parse("misere"));
+        assert_eq!(Some(Variant::Wild), parse("wild"));
+        assert_eq!(Some(Variant::Ultimate), parse("ultimate"));
+        assert_eq!(Some(Variant::Numerical), parse("numerical"));
+        assert_eq!(Some(Variant::Notakto), parse("notakto"));
+        assert_eq!(Some(Variant::OrderAndChaos), parse("order-and-chaos"));
+        assert_eq!(None, parse("reverse"));
+    }
+
+    #[test]
+    fn chooses_a_variant_from_the_menu() {
+        assert_eq!(Variant::Standard, choose(1));
+        assert_eq!(Variant::Misere, choose(2));
+        assert_eq!(Variant::Wild, choose(3));
+        assert_eq!(Variant::Ultimate, choose(4));
+        assert_eq!(Variant::Numerical, choose(5));
+        assert_eq!(Variant::Notakto, choose(6));
+        assert_eq!(Variant::OrderAndChaos, choose(7));
+        assert_eq!(Variant::Standard, choose(8));
+    }
+
+    #[test]
+    fn numbers_every_variant_in_the_menu() {
+        let menu = format_menu();
+        assert_eq!(count_choices() as usize, menu.lines().count());
+        assert!(menu.starts_with("1 - Standard\n2 - Misere"));
+        assert!(menu.ends_with(
+            "7 - Order and Chaos (6x6, either marker, Order needs five in a row)"
+        ));
+    }
+
+    #[test]
+    fn builds_the_board_each_variant_plays_on() {
+        let board = board::build_board(4);
+        let misere = build_board(Variant::Misere, board.clone(), 2);
+        assert_eq!((16, &Variant::Misere), (misere.get_number_of_spaces(), misere.get_variant()));
+        let notakto = build_board(Variant::Notakto, board.clone(), 2);
+        assert_eq!((18, &2), (notakto.get_number_of_spaces(), notakto.get_boards()));
+        let ultimate = build_board(Variant::Ultimate, board, 2);
+        assert_eq!(81, ultimate.get_number_of_spaces());
+        assert!(find_rules(Variant::Ultimate).uses_last_move());
+        assert!(!find_rules(Variant::Standard).uses_last_move());
+    }
+}
@@ -0,0 +1,45 @@
+use random;
+use marker::Marker;
+
+const SPACE_SHIFT: u32 = 32;
+const NO_MARKER_INDEX: u64 = 0;
+const LAST_MOVE_INDEX: u64 = 1;
+const X_INDEX: u64 = 2;
+const O_INDEX: u64 = 3;
+const FIRST_NUMBER_INDEX: u64 = 4;
+
+pub fn find_key(space: i32, marker: &Marker) -> u64 {
+    let index = match *marker {
+        Marker::X => X_INDEX,
+        Marker::O => O_INDEX,
+        Marker::NA => NO_MARKER_INDEX,
+        Marker::Number(value) => FIRST_NUMBER_INDEX + value as u64,
+    };
+    find_random_key(space, index)
+}
+
+pub fn find_last_move_key(space: i32) -> u64 {
+    find_random_key(space, LAST_MOVE_INDEX)
+}
+
+fn find_random_key(space: i32, index: u64) -> u64 {
+    random::build_random((space as u64) << SPACE_SHIFT | index).next_number()
+}
+
+pub mod tests {
+    #[cfg(test)]
+    use super::*;
+
+    #[test]
+    fn gives_the_same_key_every_time() {
+        assert_eq!(find_key(4, &Marker::X), find_key(4, &Marker::X));
+    }
+
+    #[test]
+    fn gives_each_space_and_marker_its_own_key() {
+        assert_ne!(find_key(4, &Marker::X), find_key(4, &Marker::O));
+        assert_ne!(find_key(4, &Marker::X), find_key(5, &Marker::X));
+        assert_ne!(find_key(4, &Marker::Number(1)), find_key(4, &Marker::Number(2)));
+        assert_ne!(find_key(4, &Marker::X), find_last_move_key(4));
+    }
+}
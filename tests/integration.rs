@@ -7,7 +7,7 @@ mod integration {
     #[test]
     fn human_vs_human_x_wins() {
         assert_cli::Assert::main_binary()
-            .stdin("1\n1\n5\n2\n4\n3\n2")
+            .stdin("1\n1\n1\n5\n2\n4\n3\n2")
             .stdout()
             .contains("Tic Tac Toe")
             .stdout()
@@ -57,7 +57,7 @@ mod integration {
     #[test]
     fn human_vs_human_o_wins() {
         assert_cli::Assert::main_binary()
-            .stdin("1\n1\n5\n2\n4\n7\n6\n2")
+            .stdin("1\n1\n1\n5\n2\n4\n7\n6\n2")
             .stdout()
             .contains("Tic Tac Toe")
             .stdout()
@@ -112,7 +112,7 @@ mod integration {
     #[test]
     fn human_vs_human_nobody_wins() {
         assert_cli::Assert::main_binary()
-            .stdin("1\n1\n5\n2\n4\n7\n8\n6\n3\n9\n2")
+            .stdin("1\n1\n1\n5\n2\n4\n7\n8\n6\n3\n9\n2")
             .stdout()
             .contains("Tic Tac Toe")
             .stdout()
@@ -175,14 +175,14 @@ mod integration {
                  --------------\n X  | O  | X  \n",
             )
             .stdout()
-            .contains("Nobody wins the game")
+            .contains("The game is a draw!")
             .unwrap();
     }
 
     #[test]
     fn computer_vs_computer() {
         assert_cli::Assert::main_binary()
-            .stdin("4\n2")
+            .stdin("4\n1\n2")
             .stdout()
             .contains("Tic Tac Toe")
             .stdout()
@@ -208,14 +208,14 @@ mod integration {
                  --------------\n 7  | 8  | 9  \n",
             )
             .stdout()
-            .contains("Nobody wins the game")
+            .contains("The game is a draw!")
             .unwrap();
     }
 
     #[test]
     fn human_vs_computer() {
         assert_cli::Assert::main_binary()
-            .stdin("2\n1\n2\n3\n4\n5\n6\n7\n8\n9\n2")
+            .stdin("2\n1\n1\n2\n3\n4\n5\n6\n7\n8\n9\n2")
             .stdout()
             .contains("Tic Tac Toe")
             .stdout()
@@ -248,7 +248,7 @@ mod integration {
     #[test]
     fn computer_vs_human() {
         assert_cli::Assert::main_binary()
-            .stdin("3\n1\n2\n3\n4\n6\n7\n8\n9\n2")
+            .stdin("3\n1\n1\n2\n3\n4\n6\n7\n8\n9\n2")
             .stdout()
             .contains("Tic Tac Toe")
             .stdout()
@@ -277,4 +277,427 @@ mod integration {
             .contains(" wins the game")
             .unwrap();
     }
+
+    #[test]
+    fn plays_unattended_games_from_the_command_line() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--mode", "4", "--games", "2", "--format", "json"])
+            .stdout()
+            .contains("{\"game\":1,\"winner\":null,\"moves\":[5,1,")
+            .stdout()
+            .contains("{\"game\":2,\"winner\":null,\"moves\":[5,1,")
+            .stdout()
+            .contains("{\"games\":2,\"x_wins\":0,\"o_wins\":0,\"draws\":2}")
+            .stdout()
+            .doesnt_contain("Select game type")
+            .unwrap();
+    }
+
+    #[test]
+    fn asks_only_for_settings_not_on_the_command_line() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--games", "1", "--first", "o"])
+            .stdin("4\n1")
+            .stdout()
+            .contains("Select game type")
+            .stdout()
+            .contains(
+                " 1  | 2  | 3  \n--------------\n 4  | O  | 6  \n\
+                 --------------\n 7  | 8  | 9  \n",
+            )
+            .stdout()
+            .contains("Games played: 1")
+            .stdout()
+            .doesnt_contain("Play again?")
+            .unwrap();
+    }
+
+    #[test]
+    fn rejects_an_unknown_command_line_option() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--colour", "red"])
+            .fails_with(2)
+            .stderr()
+            .contains("Unknown option: --colour")
+            .unwrap();
+    }
+
+    #[test]
+    fn analyses_a_position() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["analyze", "1,5,2,7"])
+            .stdout()
+            .contains("X to move\n3: X wins in 1\n4: O wins in 1")
+            .unwrap();
+    }
+
+    #[test]
+    fn counts_positions_to_a_depth() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["perft", "--depth", "2"])
+            .stdout()
+            .contains("2             72       12        0        0        0\n")
+            .stdout()
+            .contains("0 games")
+            .unwrap();
+    }
+
+    #[test]
+    fn prints_the_solved_table() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["solve"])
+            .stdout()
+            .contains("0 0 511\n")
+            .unwrap();
+    }
+
+    #[test]
+    fn rejects_an_impossible_position() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["analyze", "5,5"])
+            .fails_with(2)
+            .stderr()
+            .contains("Space 5 can not be played")
+            .unwrap();
+    }
+
+    #[test]
+    fn gives_a_human_player_a_hint() {
+        assert_cli::Assert::main_binary()
+            .stdin("1\n1\n1\n5\n2\n4\n?\n3\n2")
+            .stdout()
+            .contains("Hint: try space 3 (X wins in 1)")
+            .stdout()
+            .contains("X wins the game")
+            .stdout()
+            .contains("X used 1 hint")
+            .stdout()
+            .doesnt_contain("O used")
+            .unwrap();
+    }
+
+    #[test]
+    fn gives_a_hint_on_a_larger_board_within_the_search_budget() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--mode", "1", "--size", "4", "--games", "1", "--search-nodes", "10000"])
+            .stdin("1\n5\n2\n6\n3\n9\n?\n4")
+            .stdout()
+            .contains("Hint: try space 4 (X wins in 1)")
+            .stdout()
+            .contains("X used 1 hint")
+            .unwrap();
+    }
+
+    #[test]
+    fn human_vs_human_misere_x_completes_a_line_and_loses() {
+        assert_cli::Assert::main_binary()
+            .stdin("1\n2\n1\n5\n2\n4\n3\n2")
+            .stdout()
+            .contains("Select rules")
+            .stdout()
+            .contains(
+                " X  | X  | X  \n--------------\n O  | O  | 6  \n\
+                 --------------\n 7  | 8  | 9  \n",
+            )
+            .stdout()
+            .contains("O wins the game")
+            .unwrap();
+    }
+
+    #[test]
+    fn human_vs_human_wild_o_completes_a_line_of_x_and_wins() {
+        assert_cli::Assert::main_binary()
+            .stdin("1\n3\n1x\n5o\n2x\n3x\n2")
+            .stdout()
+            .contains("Select rules")
+            .stdout()
+            .contains("3 - Wild (place X or O on each turn)")
+            .stdout()
+            .contains(
+                " X  | X  | X  \n--------------\n 4  | O  | 6  \n\
+                 --------------\n 7  | 8  | 9  \n",
+            )
+            .stdout()
+            .contains("O wins the game")
+            .unwrap();
+    }
+
+    #[test]
+    fn analyses_a_larger_board_within_the_search_budget() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["analyze", "6,7,11,10", "--size", "4", "--search-nodes", "10000"])
+            .stdout()
+            .contains("X to move")
+            .stdout()
+            .contains("\n1: unknown")
+            .stdout()
+            .contains("\n16: unknown")
+            .unwrap();
+    }
+
+    #[test]
+    fn analyses_an_ultimate_position() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["analyze", "41", "--variant", "ultimate"])
+            .stdout()
+            .contains("    |    |    # 31 | 32 | 33 #    |    |    ")
+            .stdout()
+            .contains("O to move")
+            .stdout()
+            .contains("\n51: unknown")
+            .unwrap();
+    }
+
+    #[test]
+    fn human_vs_human_on_a_cube_x_wins_through_the_middle() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--mode", "1", "--size", "4", "--dimensions", "3", "--games", "1"])
+            .stdin("1 1 1\n4 1 1\n2 2 2\n4 1 2\n3 3 3\n4 1 3\n4 4 4")
+            .stdout()
+            .contains("Layer 1               Layer 2               Layer 3               Layer 4")
+            .stdout()
+            .contains(" X  | 2  | 3  | 4      17 | 18 | 19 | 20 ")
+            .stdout()
+            .contains("X wins the game")
+            .unwrap();
+    }
+
+    #[test]
+    fn human_vs_human_numerical_x_completes_a_line_adding_up_to_15() {
+        assert_cli::Assert::main_binary()
+            .stdin("1\n5\n1=1\n5=8\n9=7\n3=2\n7=9\n4=4\n6=3\n2")
+            .stdout()
+            .contains("5 - Numerical (odd against even numbers, a line adding up to 15 wins)")
+            .stdout()
+            .contains(
+                " =1 | 2  | =2 \n--------------\n =4 | =8 | =3 \n\
+                 --------------\n =9 | 8  | =7 \n",
+            )
+            .stdout()
+            .contains("X wins the game")
+            .unwrap();
+    }
+
+    #[test]
+    fn human_vs_human_notakto_o_kills_the_last_board_and_loses() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--boards", "2"])
+            .stdin("1\n6\n1\n2\n3\n10\n11\n12\n2")
+            .stdout()
+            .contains("6 - Notakto (both place X, whoever completes the last line loses)")
+            .stdout()
+            .contains("Board 1          Board 2")
+            .stdout()
+            .contains(" X  | X  | X      X  | X  | X  ")
+            .stdout()
+            .contains("X wins the game")
+            .unwrap();
+    }
+
+    #[test]
+    fn analyses_an_order_and_chaos_position_where_order_completes_either_line() {
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "analyze",
+                "1x,7o,2x,8o,3x,9o,4x,10o",
+                "--variant",
+                "order-and-chaos",
+            ])
+            .stdout()
+            .contains("X to move\n5x: X wins in 1\n11o: X wins in 1\n")
+            .unwrap();
+    }
+
+    #[test]
+    fn human_vs_human_o_resigns() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--mode", "1", "--variant", "standard", "--games", "1"])
+            .stdin("5\nresign\n")
+            .stdout()
+            .contains("O resigns. X wins the game!")
+            .stdout()
+            .contains("X wins: 1")
+            .unwrap();
+    }
+
+    #[test]
+    fn the_computer_accepts_a_draw_in_a_drawn_position() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--mode", "2", "--variant", "standard", "--games", "1"])
+            .stdin("1\n2\ndraw\n")
+            .stdout()
+            .contains("The players agree to a draw!")
+            .stdout()
+            .contains("Draws: 1")
+            .unwrap();
+    }
+
+    #[test]
+    fn the_computer_declines_a_draw_when_it_is_winning() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--mode", "2", "--variant", "standard", "--games", "1"])
+            .stdin("2\n8\ndraw\nresign\n")
+            .stdout()
+            .contains("O declines the draw")
+            .stdout()
+            .contains("X resigns. O wins the game!")
+            .unwrap();
+    }
+
+    #[test]
+    fn a_human_accepts_a_draw_offer() {
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "--mode",
+                "1",
+                "--variant",
+                "standard",
+                "--format",
+                "json",
+                "--games",
+                "1",
+            ])
+            .stdin("5\ndraw\n1\n")
+            .stdout()
+            .contains("{\"game\":1,\"winner\":null,\"agreed\":true,\"moves\":[5]}")
+            .unwrap();
+    }
+
+    #[test]
+    fn shows_the_time_left_under_the_board() {
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "--mode",
+                "1",
+                "--variant",
+                "standard",
+                "--games",
+                "1",
+                "--clock",
+                "300+2",
+            ])
+            .stdin("5\n1\n9\n3\n2\n8\n7\n4\n6\n")
+            .stdout()
+            .contains("X 5:00 | O 5:00")
+            .stdout()
+            .contains("X 5:01 | O 5:00")
+            .unwrap();
+    }
+
+    #[test]
+    fn adds_the_increment_only_after_a_legal_move() {
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "--mode",
+                "1",
+                "--variant",
+                "standard",
+                "--games",
+                "1",
+                "--clock",
+                "300+60",
+            ])
+            .stdin("5\n5\n1\n9\n3\n2\n8\n7\n4\n6\n")
+            .stdout()
+            .contains("That move can not be played")
+            .stdout()
+            .contains("X 5:59 | O 5:59")
+            .stdout()
+            .doesnt_contain("X 5:59 | O 6:")
+            .unwrap();
+    }
+
+    #[test]
+    fn the_rule_based_player_draws_with_the_search() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--mode", "4", "--games", "2", "--strategy", "o", "--format", "json"])
+            .stdout()
+            .contains("{\"games\":2,\"x_wins\":0,\"o_wins\":0,\"draws\":2}")
+            .unwrap();
+    }
+
+    #[test]
+    fn explains_each_computer_move() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--mode", "4", "--games", "1", "--verbose"])
+            .stdout()
+            .contains("X played 5\n1: draw\n")
+            .stdout()
+            .contains("O played 7, blocks a threat\n")
+            .unwrap();
+    }
+
+    #[test]
+    fn explains_the_search_or_rule_each_player_used_up_to_the_last_move() {
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "--mode",
+                "4",
+                "--games",
+                "1",
+                "--verbose",
+                "--mcts",
+                "x",
+                "--strategy",
+                "o",
+                "--seed",
+                "1",
+            ])
+            .stdout()
+            .contains("O played 7, blocks a threat\nRule: block\n")
+            .stdout()
+            .contains("X played 1\n1: 1000 visits, 50% won\nGames played: 1")
+            .unwrap();
+    }
+
+    #[test]
+    fn computer_players_finish_a_game_with_a_move_time() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--mode", "4", "--size", "4", "--games", "1", "--move-time", "1"])
+            .stdout()
+            .contains("Games played: 1")
+            .unwrap();
+    }
+
+    #[test]
+    fn a_node_budget_plays_the_same_game_every_time() {
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "--mode",
+                "4",
+                "--size",
+                "4",
+                "--games",
+                "1",
+                "--format",
+                "json",
+                "--search-nodes",
+                "500",
+            ])
+            .stdout()
+            .contains(
+                "{\"game\":1,\"winner\":null,\"moves\":[6,7,10,2,11,1,4,13,12,9,5,3,8,16,14,15]}",
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn a_monte_carlo_player_plays_against_the_computer() {
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "--mode",
+                "4",
+                "--mcts",
+                "o",
+                "--iterations",
+                "200",
+                "--games",
+                "2",
+                "--seed",
+                "3",
+            ])
+            .stdout()
+            .contains("Games played: 2")
+            .unwrap();
+    }
 }